@@ -1,14 +1,20 @@
-#![feature(iter_intersperse)]
-use std::{collections::BTreeMap, fs};
+//! Somebody else's day 7 solution, kept to cross-check the directory sizes
+//! of ours. Run it with `cargo run --example day7-external [<PATH>]`, it
+//! reads the cached real input, or PATH if given, `-` reading stdin.
+
+use std::{collections::BTreeMap, env, process};
+
+use aoc::{
+    input::{self, InputSource},
+    iter::IterExt,
+    store::InputStore,
+};
 
 use nom::{
     branch::alt,
     bytes::complete::{is_a, tag},
-    character::{
-        complete::{alpha1, anychar, newline, one_of},
-        is_alphabetic,
-    },
-    multi::{many1, separated_list1},
+    character::complete::{alpha1, newline},
+    multi::separated_list1,
     sequence::separated_pair,
     *,
 };
@@ -25,13 +31,15 @@ enum Cd<'a> {
     Up,
     Down(&'a str),
 }
+// Directory and file names are parsed but never needed for the sizes
+#[allow(dead_code)]
 #[derive(Debug)]
 enum Files<'a> {
     File { size: u32, name: &'a str },
     Dir(&'a str),
 }
 
-fn file(input: &str) -> IResult<&str, Files> {
+fn file(input: &str) -> IResult<&str, Files<'_>> {
     let (input, (size, name)) = separated_pair(
         nom::character::complete::u32,
         tag(" "),
@@ -39,24 +47,20 @@ fn file(input: &str) -> IResult<&str, Files> {
     )(input)?;
     Ok((input, Files::File { size, name }))
 }
-fn directory(input: &str) -> IResult<&str, Files> {
+fn directory(input: &str) -> IResult<&str, Files<'_>> {
     let (input, _) = tag("dir ")(input)?;
     let (input, name) = alpha1(input)?;
     Ok((input, Files::Dir(name)))
 }
-fn ls(input: &str) -> IResult<&str, Operation> {
+fn ls(input: &str) -> IResult<&str, Operation<'_>> {
     let (input, _) = tag("$ ls")(input)?;
     let (input, _) = newline(input)?;
-    let (input, files) = separated_list1(
-        newline,
-        alt((file, directory)),
-    )(input)?;
+    let (input, files) = separated_list1(newline, alt((file, directory)))(input)?;
     Ok((input, Operation::Ls(files)))
 }
-fn cd(input: &str) -> IResult<&str, Operation> {
+fn cd(input: &str) -> IResult<&str, Operation<'_>> {
     let (input, _) = tag("$ cd ")(input)?;
-    let (input, dir) =
-        alt((tag(".."), alpha1, tag("/")))(input)?;
+    let (input, dir) = alt((tag(".."), alpha1, tag("/")))(input)?;
     let op = match dir {
         "/" => Operation::Cd(Cd::Root),
         ".." => Operation::Cd(Cd::Up),
@@ -64,13 +68,13 @@ fn cd(input: &str) -> IResult<&str, Operation> {
     };
     Ok((input, op))
 }
-fn commands(input: &str) -> IResult<&str, Vec<Operation>> {
-    let (input, cmd) =
-        separated_list1(newline, alt((ls, cd)))(input)?;
+fn commands(input: &str) -> IResult<&str, Vec<Operation<'_>>> {
+    let (input, cmd) = separated_list1(newline, alt((ls, cd)))(input)?;
 
     Ok((input, cmd))
 }
 
+#[allow(dead_code)]
 #[derive(Debug)]
 struct File<'a> {
     size: u32,
@@ -78,8 +82,7 @@ struct File<'a> {
 }
 pub fn process_part1(input: &str) -> String {
     let cmds = commands(input).unwrap().1;
-    let mut directories: BTreeMap<String, Vec<File>> =
-        BTreeMap::new();
+    let mut directories: BTreeMap<String, Vec<File>> = BTreeMap::new();
     let mut context: Vec<&str> = vec![];
 
     for command in cmds.iter() {
@@ -99,7 +102,7 @@ pub fn process_part1(input: &str) -> String {
                         context
                             .iter()
                             .cloned()
-                            .intersperse("/")
+                            .separated_by("/")
                             .collect::<String>(),
                     )
                     .or_insert(vec![]);
@@ -111,15 +114,11 @@ pub fn process_part1(input: &str) -> String {
                                     context
                                         .iter()
                                         .cloned()
-                                        .intersperse("/")
-                                        .collect::<String>(
-                                        ),
+                                        .separated_by("/")
+                                        .collect::<String>(),
                                 )
                                 .and_modify(|vec| {
-                                    vec.push(File {
-                                        size: *size,
-                                        name,
-                                    });
+                                    vec.push(File { size: *size, name });
                                 });
                         }
                         Files::Dir(_) => (),
@@ -132,20 +131,17 @@ pub fn process_part1(input: &str) -> String {
     let mut sizes: BTreeMap<String, u32> = BTreeMap::new();
     for (path, files) in directories.iter() {
         let dirs = path.split("/").collect::<Vec<&str>>();
-        let size = files
-            .iter()
-            .map(|File { size, .. }| size)
-            .sum::<u32>();
+        let size = files.iter().map(|File { size, .. }| size).sum::<u32>();
 
         println!("path: {:?} - size: {}", path, size);
 
         for i in 0..dirs.len() {
             sizes
                 .entry(
-                    (&dirs[0..=i])
+                    dirs[0..=i]
                         .iter()
                         .cloned()
-                        .intersperse("/")
+                        .separated_by("/")
                         .collect::<String>(),
                 )
                 .and_modify(|v| *v += size)
@@ -162,8 +158,7 @@ pub fn process_part1(input: &str) -> String {
 
 pub fn process_part2(input: &str) -> String {
     let cmds = commands(input).unwrap().1;
-    let mut directories: BTreeMap<String, Vec<File>> =
-        BTreeMap::new();
+    let mut directories: BTreeMap<String, Vec<File>> = BTreeMap::new();
     let mut context: Vec<&str> = vec![];
 
     for command in cmds.iter() {
@@ -183,7 +178,7 @@ pub fn process_part2(input: &str) -> String {
                         context
                             .iter()
                             .cloned()
-                            .intersperse("/")
+                            .separated_by("/")
                             .collect::<String>(),
                     )
                     .or_insert(vec![]);
@@ -195,15 +190,11 @@ pub fn process_part2(input: &str) -> String {
                                     context
                                         .iter()
                                         .cloned()
-                                        .intersperse("/")
-                                        .collect::<String>(
-                                        ),
+                                        .separated_by("/")
+                                        .collect::<String>(),
                                 )
                                 .and_modify(|vec| {
-                                    vec.push(File {
-                                        size: *size,
-                                        name,
-                                    });
+                                    vec.push(File { size: *size, name });
                                 });
                         }
                         Files::Dir(_) => (),
@@ -216,17 +207,14 @@ pub fn process_part2(input: &str) -> String {
     let mut sizes: BTreeMap<String, u32> = BTreeMap::new();
     for (path, files) in directories.iter() {
         let dirs = path.split("/").collect::<Vec<&str>>();
-        let size = files
-            .iter()
-            .map(|File { size, .. }| size)
-            .sum::<u32>();
+        let size = files.iter().map(|File { size, .. }| size).sum::<u32>();
         for i in 0..dirs.len() {
             sizes
                 .entry(
-                    (&dirs[0..=i])
+                    dirs[0..=i]
                         .iter()
                         .cloned()
-                        .intersperse("/")
+                        .separated_by("/")
                         .collect::<String>(),
                 )
                 .and_modify(|v| *v += size)
@@ -240,8 +228,7 @@ pub fn process_part2(input: &str) -> String {
     let used_space = sizes.get("").unwrap();
 
     let current_free_space = total_size - used_space;
-    let need_to_free_at_least =
-        needed_space - current_free_space;
+    let need_to_free_at_least = needed_space - current_free_space;
 
     let mut valid_dirs = sizes
         .iter()
@@ -250,12 +237,25 @@ pub fn process_part2(input: &str) -> String {
         .collect::<Vec<&u32>>();
 
     valid_dirs.sort();
-    valid_dirs.iter().next().unwrap().to_string()
+    valid_dirs.first().unwrap().to_string()
 }
 
 fn main() {
-    let file = fs::read_to_string("days/day7/input-day7").unwrap();
-    println!("{}", process_part1(&file));
+    let source = match env::args().nth(1) {
+        None => InputSource::Real,
+        Some(path) if path == "-" => InputSource::Stdin,
+        Some(path) => InputSource::Path(path.into()),
+    };
+
+    // Never downloads, the runner does that
+    let store = InputStore::offline(InputStore::default_cache_dir());
+    match input::load(&store, &aoc_2022::YEAR, 7, &source) {
+        Ok(file) => println!("{}", process_part1(&file)),
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,25 @@
+//! Feeds arbitrary bytes to the parsers, the first byte picks the day.
+//!
+//!     cargo +nightly fuzz run parse
+
+#![no_main]
+
+use aoc::{input::InputSource, params};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&day, input)) = data.split_first() else {
+        return;
+    };
+    let Some(day) = aoc_2022::YEAR.find(day % 25 + 1) else {
+        return;
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return;
+    };
+
+    // Only the parse stage runs, no parts are asked for. Without puzzle
+    // parameters the days needing them would never get past checking them
+    let params = params::load(&aoc_2022::YEAR, day.day, &InputSource::Example).unwrap_or_default();
+    let _ = (day.solve)(input, &params, &[]);
+});
@@ -0,0 +1,53 @@
+use aoc::{parse, solution::Solution, ParseError};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    // Total calories carried by each elf
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split("\n\n")
+            .map(|x| {
+                x.trim_end().split('\n').try_fold(0u32, |total, x_str| {
+                    total
+                        .checked_add(parse::number(input, x_str)?)
+                        .ok_or_else(|| ParseError::new(input, x_str, "too many calories"))
+                })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.iter().copied().max().unwrap_or(0)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let mut a = input.clone();
+
+        a.sort();
+        a.reverse();
+
+        a.iter().take(3).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(calories in inputs::day1()) {
+            let totals: Vec<u32> = calories.0.iter().map(|items| items.iter().sum()).collect();
+
+            prop_assert_eq!(Day1::parse(&calories.to_string()).unwrap(), totals);
+        }
+    }
+}
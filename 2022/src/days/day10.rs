@@ -0,0 +1,154 @@
+use aoc::{parse, solution::Solution, visualize, Grid, ParseError};
+
+type Coordinate = aoc::Coordinate<i32>;
+
+#[derive(Debug)]
+pub enum Operation {
+    Noop,
+    Addx(i32),
+}
+
+const START_CYCLE: i32 = 20;
+const INCREMENT_FACTOR: i32 = 40;
+
+fn update_screen(screen: &mut Grid<char>, sprite_start_pos: Coordinate, crt_pos: Coordinate) {
+    if crt_pos.x <= sprite_start_pos.x + 2 && crt_pos.x >= sprite_start_pos.x {
+        let pixel = aoc::Coordinate::new(crt_pos.x as usize, crt_pos.y as usize);
+        if let Some(pixel) = screen.get_mut(pixel) {
+            *pixel = '#';
+        }
+    }
+}
+
+fn get_crt_pos(cycles_completed: i32) -> Coordinate {
+    let y = (cycles_completed as f32 / 40f32).floor() as i32;
+    Coordinate {
+        y,
+        x: cycles_completed - (y * 40),
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Operation>;
+    type Answer1 = i32;
+    // The CRT screen, the letters on it are the actual answer
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim()
+            .split('\n')
+            .map(|line| match line.split_once(' ') {
+                Some(("addx", x)) => Ok(Operation::Addx(parse::number(input, x)?)),
+                None if line == "noop" => Ok(Operation::Noop),
+                _ => Err(ParseError::new(
+                    input,
+                    line,
+                    "expected `noop` or `addx <N>`",
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(operations: &Self::Input) -> Self::Answer1 {
+        let mut cycle_to_check = START_CYCLE;
+
+        let mut value: i32 = 1;
+
+        let mut cycles_completed = 0;
+
+        let mut value_acc = 0;
+
+        operations.iter().for_each(|operation| {
+            let (new_value, condition) = match operation {
+                Operation::Noop => {
+                    cycles_completed += 1;
+
+                    (value, cycle_to_check == cycles_completed)
+                }
+                Operation::Addx(val) => {
+                    cycles_completed += 2;
+                    let new_value = value + *val;
+
+                    let d_cycles = cycles_completed - cycle_to_check;
+                    (new_value, d_cycles == 0 || d_cycles == 1)
+                }
+            };
+
+            if condition {
+                let offset = cycle_to_check * value;
+                value_acc += offset;
+                cycle_to_check += INCREMENT_FACTOR;
+            }
+
+            value = new_value;
+        });
+
+        value_acc
+    }
+
+    fn part2(operations: &Self::Input) -> Self::Answer2 {
+        let mut value: i32 = 1;
+
+        let mut cycles_completed: i32 = 0;
+
+        let mut screen = Grid::new(6, 40, '.');
+        let mut sprite_start_pos = Coordinate::default();
+
+        operations.iter().for_each(|operation| {
+            let mut crt_pos = get_crt_pos(cycles_completed);
+
+            let offset = match operation {
+                Operation::Noop => {
+                    cycles_completed += 1;
+                    update_screen(&mut screen, sprite_start_pos, crt_pos);
+                    0
+                }
+                Operation::Addx(val) => {
+                    cycles_completed += 1;
+                    update_screen(&mut screen, sprite_start_pos, crt_pos);
+
+                    crt_pos = get_crt_pos(cycles_completed);
+
+                    cycles_completed += 1;
+                    update_screen(&mut screen, sprite_start_pos, crt_pos);
+
+                    *val
+                }
+            };
+
+            value += offset;
+            visualize::push(|| screen.clone());
+
+            sprite_start_pos.x = value - 1;
+        });
+
+        screen.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(program in inputs::day10()) {
+            let parsed: Vec<Option<i32>> = Day10::parse(&program.to_string())
+                .unwrap()
+                .iter()
+                .map(|operation| match operation {
+                    Operation::Noop => None,
+                    Operation::Addx(x) => Some(*x),
+                })
+                .collect();
+
+            prop_assert_eq!(parsed, program.0);
+        }
+    }
+}
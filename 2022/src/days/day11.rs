@@ -0,0 +1,258 @@
+use std::fmt::Debug;
+
+use aoc::{parse, solution::Solution, ParseError};
+
+pub struct Monkey {
+    items_worry_levels: Vec<u128>,
+    operation: Box<dyn Fn(u128) -> u128>,
+    test: Box<dyn Fn(u128) -> usize>,
+}
+
+impl Debug for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Monkey")
+            .field("starting_items", &self.items_worry_levels)
+            .field("operation", &(self.operation)(2u128))
+            .field("test", &(self.test)(2u128))
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+pub struct Troop {
+    monkeys: Vec<Monkey>,
+    // Product of every monkey's divisor, worry levels can be kept modulo
+    // this without changing any test outcome
+    lcm: u128,
+}
+
+// `None` for an unknown operator or operand
+fn make_operation_closure(operation: &str, var: &str) -> Option<Box<dyn Fn(u128) -> u128>> {
+    let var_res = var.parse::<u128>();
+    if let Ok(var) = var_res {
+        match operation {
+            "+" => Some(Box::new(move |x| var + x)),
+            "-" => Some(Box::new(move |x| var - x)),
+            "*" => Some(Box::new(move |x| var * x)),
+            "/" => Some(Box::new(move |x| (var as f32 / x as f32).round() as u128)),
+            _ => None,
+        }
+    } else if var == "old" {
+        match operation {
+            "+" => Some(Box::new(|x| x + x)),
+            "-" => Some(Box::new(|_| 0)),
+            "*" => Some(Box::new(|x| x * x)),
+            "/" => Some(Box::new(|_| 1)),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+// Returns the level of monkey business after `total_rounds`, `relief`
+// is applied to every worry level after a monkey inspects an item
+fn play_rounds(troop: &Troop, total_rounds: u32, relief: impl Fn(u128) -> u128) -> u64 {
+    let monkeys = &troop.monkeys;
+    let n_monkeys = monkeys.len();
+
+    let mut monkeys_items: Vec<Vec<u128>> = monkeys
+        .iter()
+        .map(|monkey| monkey.items_worry_levels.clone())
+        .collect();
+
+    let mut inspection_count = vec![0u64; n_monkeys];
+
+    for _round in 0..total_rounds {
+        for i in 0..n_monkeys {
+            let monkey = &monkeys[i];
+            let mut items_to_pass = vec![];
+
+            monkeys_items[i].iter().for_each(|item_worry_level| {
+                let worry_level_after_hold = (monkey.operation)(*item_worry_level);
+
+                let worry_level_after_leave = relief(worry_level_after_hold);
+
+                let pass_to_monkey_idx = (monkey.test)(worry_level_after_leave);
+
+                items_to_pass.push((pass_to_monkey_idx, worry_level_after_leave));
+                inspection_count[i] += 1;
+            });
+
+            monkeys_items[i] = vec![];
+
+            for item_to_pass in items_to_pass {
+                monkeys_items[item_to_pass.0].push(item_to_pass.1);
+            }
+        }
+    }
+
+    inspection_count.sort_by(|a, b| b.cmp(a));
+    inspection_count[0] * inspection_count[1]
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Troop;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lcm = 1;
+        // Every monkey an item is thrown to, checked once the number of
+        // monkeys is known
+        let mut throw_targets = vec![];
+
+        let monkeys = input
+            .trim()
+            .split("\n\n")
+            .map(|monkey_info| {
+                let lines = monkey_info.split('\n').skip(1).collect::<Vec<&str>>();
+                let [items_line, operation_line, test_line, true_line, false_line] = lines[..]
+                else {
+                    return Err(ParseError::new(
+                        input,
+                        monkey_info,
+                        "expected a monkey followed by 5 lines describing it",
+                    ));
+                };
+
+                // starting items
+                let (_, numbers_str_list) = parse::split_once(input, items_line, ":")?;
+                let numbers_list = numbers_str_list
+                    .split(',')
+                    .map(|number_str| parse::number::<u128>(input, number_str.trim()))
+                    .collect::<Result<Vec<u128>, ParseError>>()?;
+
+                // operation
+                let (_, expr_str) = parse::split_once(input, operation_line, "= old ")?;
+                let (operator, operand) = parse::split_once(input, expr_str, " ")?;
+
+                let operation = make_operation_closure(operator, operand).ok_or_else(|| {
+                    ParseError::new(input, expr_str, "expected an operation like `* 19`")
+                })?;
+
+                // test
+                let (_, divisible_by_str) =
+                    parse::split_once(input, test_line, "Test: divisible by ")?;
+                let divisible_by = parse::number::<u128>(input, divisible_by_str)?;
+                if divisible_by == 0 {
+                    return Err(ParseError::new(
+                        input,
+                        divisible_by_str,
+                        "expected a divisor above 0",
+                    ));
+                }
+
+                lcm *= divisible_by;
+
+                let (_, true_monkey_idx_str) =
+                    parse::split_once(input, true_line, "If true: throw to monkey ")?;
+                let true_monkey_idx = parse::number::<usize>(input, true_monkey_idx_str)?;
+
+                throw_targets.push((true_monkey_idx_str, true_monkey_idx));
+
+                let (_, false_monkey_idx_str) =
+                    parse::split_once(input, false_line, "If false: throw to monkey ")?;
+                let false_monkey_idx = parse::number::<usize>(input, false_monkey_idx_str)?;
+                throw_targets.push((false_monkey_idx_str, false_monkey_idx));
+
+                Ok(Monkey {
+                    items_worry_levels: numbers_list,
+                    operation,
+                    test: Box::new(move |x| {
+                        if x % divisible_by == 0 {
+                            true_monkey_idx
+                        } else {
+                            false_monkey_idx
+                        }
+                    }),
+                })
+            })
+            .collect::<Result<Vec<Monkey>, ParseError>>()?;
+
+        // The monkey business multiplies the two busiest monkeys
+        if monkeys.len() < 2 {
+            return Err(ParseError::without_location("expected at least 2 monkeys"));
+        }
+        for (target_str, target) in throw_targets {
+            if target >= monkeys.len() {
+                return Err(ParseError::new(
+                    input,
+                    target_str,
+                    format!("expected a monkey below {}", monkeys.len()),
+                ));
+            }
+        }
+
+        Ok(Troop { monkeys, lcm })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        play_rounds(input, 20, |worry_level| worry_level / 3)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        play_rounds(input, 10000, |worry_level| worry_level % input.lcm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(notes in inputs::day11()) {
+            let troop = Day11::parse(&notes.to_string()).unwrap();
+
+            prop_assert_eq!(troop.monkeys.len(), notes.0.len());
+            for (monkey, expected) in troop.monkeys.iter().zip(&notes.0) {
+                prop_assert_eq!(&monkey.items_worry_levels, &expected.items);
+                for old in 1..10 {
+                    prop_assert_eq!((monkey.operation)(old), expected.operation(old));
+                }
+                prop_assert_eq!((monkey.test)(expected.divisor * 3), expected.if_true);
+                prop_assert_eq!((monkey.test)(expected.divisor * 3 + 1), expected.if_false);
+            }
+            prop_assert_eq!(
+                troop.lcm,
+                notes.0.iter().map(|monkey| monkey.divisor).product::<u128>()
+            );
+        }
+
+        #[test]
+        fn unknown_throw_targets_are_rejected(
+            mut notes in inputs::day11(),
+            target in 8..100usize,
+            if_true: bool,
+        ) {
+            let last = notes.0.last_mut().unwrap();
+            if if_true {
+                last.if_true = target;
+            } else {
+                last.if_false = target;
+            }
+
+            let err = Day11::parse(&notes.to_string()).unwrap_err();
+
+            prop_assert_eq!(err.message, format!("expected a monkey below {}", notes.0.len()));
+            prop_assert!(err.location.is_some());
+        }
+
+        #[test]
+        fn a_lone_monkey_is_rejected(mut notes in inputs::day11()) {
+            notes.0.truncate(1);
+            let monkey = &mut notes.0[0];
+            (monkey.if_true, monkey.if_false) = (0, 0);
+
+            let err = Day11::parse(&notes.to_string()).unwrap_err();
+
+            prop_assert_eq!(err.message, "expected at least 2 monkeys");
+        }
+    }
+}
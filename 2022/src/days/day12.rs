@@ -0,0 +1,125 @@
+use std::collections::VecDeque;
+
+use aoc::{grid::Position, parallel, parse, solution::Solution, visualize, Grid, ParseError};
+
+fn get_level(ch: char) -> i32 {
+    if ch == 'S' {
+        'a' as i32
+    } else if ch == 'E' {
+        'z' as i32
+    } else {
+        ch as i32
+    }
+}
+
+// Squares which can be stepped onto from `pos`, at most one level higher
+fn get_adj_nodes(heightmap: &Grid<char>, pos: Position) -> impl Iterator<Item = Position> + '_ {
+    let curr_level = get_level(heightmap[pos]);
+
+    heightmap
+        .neighbours4(pos)
+        .filter(move |&adj_pos| (curr_level - get_level(heightmap[adj_pos])) >= -1)
+}
+
+fn find_shortest_path(heightmap: &Grid<char>, start_pos: Position) -> i32 {
+    let mut queue = VecDeque::new();
+    let mut visited = Grid::new(heightmap.rows(), heightmap.cols(), false);
+    let mut dist = Grid::new(heightmap.rows(), heightmap.cols(), 0);
+
+    queue.push_back(start_pos);
+    visited[start_pos] = true;
+
+    let mut end_goal_dist = -1;
+
+    while let Some(pos) = queue.pop_front() {
+        for adj_pos in get_adj_nodes(heightmap, pos) {
+            if !visited[adj_pos] {
+                visited[adj_pos] = true;
+                dist[adj_pos] = dist[pos] + 1;
+                queue.push_back(adj_pos);
+
+                if heightmap[adj_pos] == 'E' {
+                    end_goal_dist = dist[adj_pos];
+                    break;
+                }
+
+                // Visited squares over the heightmap
+                visualize::push(|| {
+                    let mut frame = heightmap.clone();
+                    for (pos, _) in visited.iter().filter(|(_, &seen)| seen) {
+                        frame[pos] = 'X';
+                    }
+                    frame
+                });
+            }
+        }
+    }
+
+    end_goal_dist
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let heightmap = parse::grid(input, "expected a height, a-z, S or E", |ch| {
+            (ch.is_ascii_lowercase() || ch == 'S' || ch == 'E').then_some(ch)
+        })?;
+
+        for marker in ['S', 'E'] {
+            if !heightmap.iter().any(|(_, &ch)| ch == marker) {
+                return Err(ParseError::without_location(format!(
+                    "expected a square marked {marker}"
+                )));
+            }
+        }
+
+        Ok(heightmap)
+    }
+
+    fn part1(heightmap: &Self::Input) -> Self::Answer1 {
+        let (start_pos, _) = heightmap
+            .iter()
+            .find(|(_, &ch)| ch == 'S')
+            .expect("start position is checked while parsing");
+
+        find_shortest_path(heightmap, start_pos)
+    }
+
+    fn part2(heightmap: &Self::Input) -> Self::Answer2 {
+        let start_positions: Vec<Position> = heightmap
+            .iter()
+            .filter(|(_, &ch)| ch == 'S' || ch == 'a')
+            .map(|(pos, _)| pos)
+            .collect();
+
+        // Every search is independent of the others
+        parallel::map(&start_positions, |&pos| find_shortest_path(heightmap, pos))
+            .into_iter()
+            .filter(|&end_goal_dist| end_goal_dist != -1)
+            .min()
+            .unwrap_or(i32::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(heightmap in inputs::day12()) {
+            prop_assert_eq!(
+                Day12::parse(&heightmap.to_string()).unwrap(),
+                Grid::from_rows(heightmap.0).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    cmp::{self, Ordering},
+    slice,
+};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete as cc,
+    combinator::{cut, map},
+    multi::separated_list0,
+    sequence::delimited,
+    IResult,
+};
+
+use aoc::{parse, solution::Solution, ParseError};
+
+#[derive(Debug, Clone)]
+pub struct Pair {
+    left: Packet,
+    right: Packet,
+}
+
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+fn parse_packet(line: &str) -> IResult<&str, Packet> {
+    alt((
+        map(
+            delimited(
+                tag("["),
+                separated_list0(tag(","), parse_packet),
+                cut(tag("]")),
+            ),
+            Packet::List,
+        ),
+        map(cc::u32, Packet::Integer),
+    ))(line)
+}
+
+// Parses `line` as a single packet with nothing following it
+fn parse_whole_packet(input: &str, line: &str) -> Result<Packet, ParseError> {
+    match parse_packet(line) {
+        Ok(("", packet)) => Ok(packet),
+        Ok((rest, _)) => Err(ParseError::new(input, rest, "expected end of packet")),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            // Point at the first unexpected character only
+            let unexpected_len = err.input.chars().next().map_or(0, char::len_utf8);
+            Err(ParseError::new(
+                input,
+                &err.input[..unexpected_len],
+                "unexpected character in packet",
+            ))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(input, line, "incomplete packet")),
+    }
+}
+
+// Some(true) -> correct order
+// Some(false) -> incorrect order
+// None -> both are same
+fn check_integer_order(int_left: u32, int_right: u32) -> Option<bool> {
+    if int_left == int_right {
+        None
+    } else {
+        Some(int_left < int_right)
+    }
+}
+
+fn check_list(list_left: &[Packet], list_right: &[Packet]) -> Option<bool> {
+    let min_len = cmp::min(list_left.len(), list_right.len());
+
+    for i in 0..min_len {
+        match check_order(&list_left[i], &list_right[i]) {
+            Some(result) => {
+                return Some(result);
+            }
+            None => continue,
+        }
+    }
+
+    // We transversed list till have same number
+    // of elements and we did not get any results
+    // i.e. both have exact same integers till now
+
+    if list_left.len() == min_len && list_right.len() == min_len {
+        return None;
+    }
+
+    Some(list_left.len() == min_len)
+}
+
+fn check_order(left_packet: &Packet, right_packet: &Packet) -> Option<bool> {
+    match (left_packet, right_packet) {
+        (Packet::List(list_left), Packet::List(list_right)) => check_list(list_left, list_right),
+        (Packet::Integer(int_left), Packet::Integer(int_right)) => {
+            check_integer_order(*int_left, *int_right)
+        }
+        (Packet::List(list_left), Packet::Integer(_int_right)) => {
+            check_list(list_left, slice::from_ref(right_packet))
+        }
+        (Packet::Integer(_int_left), Packet::List(list_right)) => {
+            check_list(slice::from_ref(left_packet), list_right)
+        }
+    }
+}
+
+// Equal packets are in neither order
+fn compare(left_packet: &Packet, right_packet: &Packet) -> Ordering {
+    match check_order(left_packet, right_packet) {
+        Some(true) => Ordering::Less,
+        Some(false) => Ordering::Greater,
+        None => Ordering::Equal,
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim_end()
+            .split("\n\n")
+            .map(|pair_lines| {
+                let (line1, line2) = parse::split_once(input, pair_lines, "\n")?;
+
+                Ok(Pair {
+                    left: parse_whole_packet(input, line1)?,
+                    right: parse_whole_packet(input, line2)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input) -> Self::Answer1 {
+        let mut sum_of_indices = 0;
+        for (i, pair) in pairs.iter().enumerate() {
+            // Identical packets are not out of order
+            if compare(&pair.left, &pair.right) != Ordering::Greater {
+                sum_of_indices += i + 1;
+            }
+        }
+
+        sum_of_indices
+    }
+
+    fn part2(pairs: &Self::Input) -> Self::Answer2 {
+        let packet_2 = Packet::List(vec![Packet::List(vec![Packet::Integer(2)])]);
+        let packet_6 = Packet::List(vec![Packet::List(vec![Packet::Integer(6)])]);
+
+        // Flagged, so a divider is told apart from a packet equal to it
+        let mut packets: Vec<(&Packet, bool)> = pairs
+            .iter()
+            .flat_map(|pair| [(&pair.left, false), (&pair.right, false)])
+            .chain([(&packet_2, true), (&packet_6, true)])
+            .collect();
+
+        packets.sort_by(|(first, _), (second, _)| compare(first, second));
+
+        packets
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, divider))| *divider)
+            .map(|(i, _)| i + 1)
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    // Writes the packet back out the way it is written in the input
+    fn render(packet: &Packet) -> String {
+        match packet {
+            Packet::Integer(integer) => integer.to_string(),
+            Packet::List(packets) => {
+                let packets: Vec<String> = packets.iter().map(render).collect();
+                format!("[{}]", packets.join(","))
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(packets in inputs::day13()) {
+            let parsed: Vec<(String, String)> = Day13::parse(&packets.to_string())
+                .unwrap()
+                .iter()
+                .map(|pair| (render(&pair.left), render(&pair.right)))
+                .collect();
+
+            prop_assert_eq!(parsed, packets.0);
+        }
+    }
+
+    #[test]
+    fn identical_packets_are_in_order() {
+        let pairs = Day13::parse("[1]\n[1]\n\n[[2]]\n[1]\n").unwrap();
+
+        assert_eq!(Day13::part1(&pairs), 1);
+        // Three [1], then [[2]], the [[2]] divider and the [[6]] divider
+        assert_eq!(Day13::part2(&pairs), 5 * 6);
+    }
+}
@@ -0,0 +1,219 @@
+use aoc::{parse, solution::Solution, visualize, ParseError, SparseGrid};
+
+type Coordinate = aoc::Coordinate<i64>;
+
+fn get_coords_between(start_coord: Coordinate, end_coord: Coordinate) -> Vec<Coordinate> {
+    let diff = start_coord - end_coord;
+
+    let mut coords_between = vec![];
+
+    let sign;
+    if diff.x == 0 {
+        sign = -diff.y.signum();
+        let mut coord = start_coord;
+        // Here we only go till -1 as
+        // the last coord is not an
+        // in "between" element
+        for _ in 0..diff.y.abs() - 1 {
+            coord = coord + Coordinate { x: 0, y: sign };
+            coords_between.push(coord);
+        }
+    } else {
+        sign = -diff.x.signum();
+        let mut coord = start_coord;
+        for _ in 0..diff.x.abs() - 1 {
+            coord = coord + Coordinate { x: sign, y: 0 };
+            coords_between.push(coord);
+        }
+    }
+
+    coords_between
+}
+
+// Largest coordinate of a rock, every position of the paths is stored so a
+// path spanning billions of positions would not fit in memory. Scans only
+// cover the cave around the sand source
+const MAX_COORD: i64 = 10_000;
+
+fn parse_coord(input: &str, coord_str: &str) -> Result<i64, ParseError> {
+    let coord = parse::number(input, coord_str)?;
+    if !(0..=MAX_COORD).contains(&coord) {
+        return Err(ParseError::new(
+            input,
+            coord_str,
+            format!("expected a coordinate between 0 and {MAX_COORD}"),
+        ));
+    }
+
+    Ok(coord)
+}
+
+// Where sand falls from
+const SAND_SOURCE: Coordinate = Coordinate { x: 500, y: 0 };
+
+// Sand tries to fall straight down, then diagonally left, then diagonally
+// right
+const FALL_STEPS: [Coordinate; 3] = [
+    Coordinate::new(0, 1),
+    Coordinate::new(-1, 1),
+    Coordinate::new(1, 1),
+];
+
+#[derive(Debug, Clone, Copy)]
+enum Bottom {
+    // Sand below this row keeps falling forever
+    Abyss(i64),
+    // An infinitely wide floor of rock on this row
+    Floor(i64),
+}
+
+// Drops one grain from `SAND_SOURCE`, returns where it comes to rest or
+// `None` once it falls into the abyss
+fn drop_sand(cave: &SparseGrid<char>, bottom: Bottom) -> Option<Coordinate> {
+    let mut sand_coord = SAND_SOURCE;
+
+    'falling: loop {
+        if let Bottom::Abyss(abyss_y) = bottom {
+            if sand_coord.y > abyss_y {
+                return None;
+            }
+        }
+
+        for step in FALL_STEPS {
+            let next_coord = sand_coord + step;
+            let on_floor = matches!(bottom, Bottom::Floor(floor_y) if next_coord.y == floor_y);
+
+            if !on_floor && !cave.contains(next_coord) {
+                sand_coord = next_coord;
+                continue 'falling;
+            }
+        }
+
+        return Some(sand_coord);
+    }
+}
+
+// Drops grains until one falls into the abyss or the source gets blocked
+fn count_resting_sand(scan: &SparseGrid<char>, bottom: Bottom) -> usize {
+    let mut cave = scan.clone();
+
+    let mut cnt = 0;
+    while let Some(sand_coord) = drop_sand(&cave, bottom) {
+        cave.insert(sand_coord, 'o');
+        cnt += 1;
+        visualize::push(|| cave.to_grid(|cell| cell.copied().unwrap_or('.')));
+
+        if sand_coord == SAND_SOURCE {
+            break;
+        }
+    }
+
+    cnt
+}
+
+// Lowest row of rock in the scan
+fn max_y(scan: &SparseGrid<char>) -> i64 {
+    let (_, max) = scan.bounds().expect("invalid input: expected rocks");
+    max.y
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    // Every rock, including the ones between path corners
+    type Input = SparseGrid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut rocks = SparseGrid::new();
+
+        for pair_lines in input.trim().split("\n") {
+            let mut previous_coord_opt = None;
+            for coord_str in pair_lines.split(" -> ") {
+                let (x_str, y_str) = parse::split_once(input, coord_str, ",")?;
+
+                let curr_coord = Coordinate {
+                    x: parse_coord(input, x_str)?,
+                    y: parse_coord(input, y_str)?,
+                };
+
+                if let Some(previous_coord) = previous_coord_opt {
+                    let diff: Coordinate = curr_coord - previous_coord;
+                    if diff.x != 0 && diff.y != 0 {
+                        return Err(ParseError::new(
+                            input,
+                            coord_str,
+                            "expected a horizontal or vertical path",
+                        ));
+                    }
+                }
+
+                let mut coords_between = match previous_coord_opt {
+                    Some(previous_coord) => get_coords_between(previous_coord, curr_coord),
+                    None => vec![],
+                };
+
+                previous_coord_opt = Some(curr_coord);
+                coords_between.push(curr_coord);
+
+                for coord in coords_between {
+                    rocks.insert(coord, '#');
+                }
+            }
+        }
+
+        Ok(rocks)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        count_resting_sand(input, Bottom::Abyss(max_y(input)))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        count_resting_sand(input, Bottom::Floor(max_y(input) + 2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(scan in inputs::day14()) {
+            let rocks: HashSet<(i64, i64)> = Day14::parse(&scan.to_string())
+                .unwrap()
+                .iter()
+                .map(|(coord, _)| (coord.x, coord.y))
+                .collect();
+
+            prop_assert_eq!(rocks, scan.rocks());
+        }
+
+        #[test]
+        fn far_away_rocks_are_rejected(far in MAX_COORD + 1..i64::MAX, vertical: bool) {
+            let path = if vertical {
+                format!("500,0 -> 500,{far}")
+            } else {
+                format!("0,0 -> {far},0")
+            };
+
+            prop_assert!(Day14::parse(&path).is_err());
+        }
+    }
+
+    #[test]
+    fn long_path_is_a_located_error() {
+        let err = Day14::parse("0,0 -> 99999999999999,0").unwrap_err();
+
+        assert_eq!(err.message, "expected a coordinate between 0 and 10000");
+        let location = err.location.unwrap();
+        assert_eq!((location.line, location.column), (1, 8));
+    }
+}
@@ -0,0 +1,453 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use aoc::{
+    diamond::Rotated,
+    grid::Position,
+    parallel,
+    params::Params,
+    parse,
+    solution::{Answer, Solution},
+    visualize::{self, Frame},
+    Diamond, Grid, IntervalSet, ParseError,
+};
+
+type Coordinate = aoc::Coordinate<i64>;
+
+// Parses the `x=<N>, y=<N>` at the end of `text`
+fn parse_x_y(input: &str, text: &str) -> Result<Coordinate, ParseError> {
+    let (_, x_y) = parse::split_once(input, text, "x=")?;
+    let (x, y) = parse::split_once(input, x_y, ", y=")?;
+
+    Ok(Coordinate {
+        x: parse::number(input, x)?,
+        y: parse::number(input, y)?,
+    })
+}
+
+#[derive(Debug)]
+struct MaxMin {
+    max_x: i64,
+    min_x: i64,
+    min_y: i64,
+    max_y: i64,
+}
+
+impl MaxMin {
+    fn new() -> Self {
+        Self {
+            max_x: i64::MIN,
+            min_x: i64::MAX,
+            min_y: i64::MAX,
+            max_y: i64::MIN,
+        }
+    }
+
+    fn update(&mut self, coord: &Coordinate) {
+        if coord.x > self.max_x {
+            self.max_x = coord.x;
+        }
+
+        if coord.y > self.max_y {
+            self.max_y = coord.y;
+        }
+
+        if coord.x < self.min_x {
+            self.min_x = coord.x;
+        }
+
+        if coord.y < self.min_y {
+            self.min_y = coord.y;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SensorInfo {
+    coord: Coordinate,
+    // Positions no further away than the closest beacon, where no other
+    // beacon can be
+    coverage: Diamond<i64>,
+}
+
+impl SensorInfo {
+    // The columns of row `y_to_check` which are covered, if the sensor
+    // reaches the row
+    fn find_collinear_point(&self, y_to_check: i64) -> Option<RangeInclusive<i64>> {
+        self.coverage.row(y_to_check)
+    }
+
+    fn lies_in_coverage(&self, coord: &Coordinate) -> bool {
+        self.coverage.contains(*coord)
+    }
+}
+
+const TUNING_FREQUENCY_X: i64 = 4000000;
+
+// A position in the square from `0, 0` to `bound, bound` no sensor covers.
+// The square is swept one diagonal `x + y = u` at a time: along a diagonal
+// every sensor covers a fixed range of `v = x - y`, so the coverage only
+// changes where the diamond of a sensor starts or ends
+fn find_gap(sensors: &[SensorInfo], bound: i64) -> Option<Coordinate> {
+    let last_u = 2 * bound;
+
+    let mut starts = vec![0];
+    for sensor in sensors {
+        let u_range = sensor.coverage.u_range();
+        starts.extend(
+            [*u_range.start(), *u_range.end() + 1]
+                .into_iter()
+                .filter(|u| (0..=last_u).contains(u)),
+        );
+    }
+    starts.sort_unstable();
+    starts.dedup();
+
+    for (idx, &start) in starts.iter().enumerate() {
+        let end = starts.get(idx + 1).map_or(last_u, |next| next - 1);
+
+        let covered: IntervalSet<i64> = sensors
+            .iter()
+            .filter(|sensor| sensor.coverage.u_range().contains(&start))
+            .map(|sensor| sensor.coverage.v_range())
+            .collect();
+        let gaps = IntervalSet::from_iter([-bound..=bound]).difference(&covered);
+
+        for gap in gaps.iter() {
+            // Inside the square, diagonals are cut off at `u = |v|` and
+            // `u = 2 * bound - |v|`. So of either parity the `v` closest to
+            // 0 leaves the most diagonals to pick from
+            let closest = 0.clamp(*gap.start(), *gap.end());
+            for v in [closest, closest - 1, closest + 1] {
+                if !gap.contains(&v) {
+                    continue;
+                }
+
+                // `u` and `v` of a position are both even or both odd
+                let first = start.max(v.abs());
+                let u = first + (first - v).rem_euclid(2);
+                if u <= end.min(last_u - v.abs()) {
+                    return Rotated { u, v }.to_coordinate();
+                }
+            }
+        }
+    }
+
+    None
+}
+
+type Sensors = (Vec<SensorInfo>, HashSet<Coordinate>, MaxMin);
+
+fn parse_sensors(input: &str) -> Result<Sensors, ParseError> {
+    // For this question, our convention of converting given
+    // coordinate to indexable numbers is:
+    // x -> col it belongs to
+    // y -> row it belongs to
+
+    let mut max_min = MaxMin::new();
+
+    let mut sensors = vec![];
+    let mut beacons: HashSet<Coordinate> = HashSet::new();
+
+    for sensor_beacon_info in input.trim().split("\n") {
+        let (sensor_info, beacon_info) = parse::split_once(input, sensor_beacon_info, ":")?;
+
+        // Calculate Sensor Information
+        let sensor_coord = parse_x_y(input, sensor_info)?;
+
+        // Calculate Beacon Information
+        let beacon_coord = parse_x_y(input, beacon_info)?;
+
+        beacons.insert(beacon_coord);
+
+        max_min.update(&sensor_coord);
+        max_min.update(&beacon_coord);
+
+        let dist_from_beacon = sensor_coord.manhattan_distance(beacon_coord);
+
+        let sensor_info = SensorInfo {
+            coord: sensor_coord,
+            coverage: Diamond::around(sensor_coord, dist_from_beacon),
+        };
+
+        sensors.push(sensor_info);
+    }
+
+    Ok((sensors, beacons, max_min))
+}
+
+#[derive(Debug)]
+pub struct SensorReport {
+    sensors: Vec<SensorInfo>,
+    beacons: HashSet<Coordinate>,
+    // Around the sensors and beacons, the area drawn by `report_frame`
+    bounds: MaxMin,
+    // Puzzle parameters, see `aoc::params`. The row part 1 asks about
+    row_to_check: i64,
+    // Both coordinates of the distress beacon are from 0 up to this
+    search_bound: i64,
+}
+
+// Frames of the real input are shrunk to at most this many cells a side,
+// every cell then stands for a square of positions. The example is small
+// enough to be drawn position by position, like the map of the puzzle
+const MAX_FRAME_SIDE: i64 = 200;
+
+// Draws the sensors `S`, their beacons `B` and the positions they cover `#`
+// within `bounds`. Positions of the checked row are `=` where covered and
+// `-` where not, and the gap of part 2 is an `X`
+fn report_frame(report: &SensorReport, gap: Option<Coordinate>) -> Frame {
+    let bounds = &report.bounds;
+    let width = bounds.max_x - bounds.min_x + 1;
+    let height = bounds.max_y - bounds.min_y + 1;
+    let scale = ((width.max(height) + MAX_FRAME_SIDE - 1) / MAX_FRAME_SIDE).max(1);
+
+    let cols = ((width + scale - 1) / scale) as usize;
+    let rows = ((height + scale - 1) / scale) as usize;
+    let cell = |coord: Coordinate| {
+        ((bounds.min_x..=bounds.max_x).contains(&coord.x)
+            && (bounds.min_y..=bounds.max_y).contains(&coord.y))
+        .then(|| {
+            Position::new(
+                ((coord.x - bounds.min_x) / scale) as usize,
+                ((coord.y - bounds.min_y) / scale) as usize,
+            )
+        })
+    };
+    // The middle of the square of positions a cell stands for
+    let position = |cell: Position| {
+        Coordinate::new(
+            bounds.min_x + cell.x as i64 * scale + scale / 2,
+            bounds.min_y + cell.y as i64 * scale + scale / 2,
+        )
+    };
+    let covered = |coord: Coordinate| {
+        report
+            .sensors
+            .iter()
+            .any(|sensor| sensor.lies_in_coverage(&coord))
+    };
+
+    let mut frame = Grid::new(rows, cols, '.');
+    for pos in frame.positions().collect::<Vec<_>>() {
+        if covered(position(pos)) {
+            frame[pos] = '#';
+        }
+    }
+
+    if let Some(row) = cell(Coordinate::new(bounds.min_x, report.row_to_check)) {
+        for col in 0..cols {
+            let pos = Position::new(col, row.y);
+            let on_row = Coordinate::new(position(pos).x, report.row_to_check);
+            frame[pos] = if covered(on_row) { '=' } else { '-' };
+        }
+    }
+
+    let beacons = report.beacons.iter().map(|&beacon| (beacon, 'B'));
+    let sensors = report.sensors.iter().map(|sensor| (sensor.coord, 'S'));
+    for (coord, ch) in beacons.chain(sensors).chain(gap.map(|gap| (gap, 'X'))) {
+        if let Some(pos) = cell(coord) {
+            frame[pos] = ch;
+        }
+    }
+
+    frame
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = SensorReport;
+    // Positions in `row_to_check` where a beacon cannot be present
+    type Answer1 = i64;
+    // Tuning frequency of the only position within `search_bound` where
+    // the distress beacon can be
+    type Answer2 = Answer;
+
+    // The rows asked about differ between the example and the real input,
+    // so the report can not be solved without its parameters
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let (sensors, beacons, bounds) = parse_sensors(input)?;
+
+        Ok(SensorReport {
+            sensors,
+            beacons,
+            bounds,
+            row_to_check: params.get("row_to_check")?,
+            search_bound: params.get("search_bound")?,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        visualize::push(|| report_frame(input, None));
+
+        // The sensors are checked independently, then their ranges merged
+        let mut covered: IntervalSet<i64> = parallel::map(&input.sensors, |sensor_info| {
+            sensor_info.find_collinear_point(input.row_to_check)
+        })
+        .into_iter()
+        .flatten()
+        .collect();
+
+        // A beacon's own position is the one place on its row where a
+        // beacon can be
+        for beacon in input
+            .beacons
+            .iter()
+            .filter(|beacon| beacon.y == input.row_to_check)
+        {
+            covered.remove(beacon.x..=beacon.x);
+        }
+
+        covered.len()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let gap = find_gap(&input.sensors, input.search_bound);
+        visualize::push(|| report_frame(input, gap));
+
+        match gap {
+            Some(gap) => (gap.x * TUNING_FREQUENCY_X + gap.y).into(),
+            None => "every position is covered".into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    fn sensors(report: &inputs::SensorReport) -> Vec<SensorInfo> {
+        let (sensors, _, _) = parse_sensors(&report.to_string()).unwrap();
+        sensors
+    }
+
+    // Small reports, so that the search square can be checked position by
+    // position and gaps in it are common
+    fn small_report() -> impl Strategy<Value = inputs::SensorReport> {
+        prop::collection::vec(((-5..25i64, -5..25i64), (-8..8i64, -8..8i64)), 1..12)
+            .prop_map(|sensors| {
+                sensors
+                    .into_iter()
+                    .map(|((x, y), (dx, dy))| ((x, y), (x + dx, y + dy)))
+                    .collect()
+            })
+            .prop_map(inputs::SensorReport)
+    }
+
+    #[test]
+    fn finds_gap_between_parallel_boundaries() {
+        let sensors = sensors(&inputs::SensorReport(vec![
+            ((2, 11), (5, 16)),
+            ((2, 4), (-4, 4)),
+            ((12, 1), (14, 1)),
+            ((10, 10), (16, 10)),
+            ((9, -1), (8, -6)),
+            ((9, 4), (11, 4)),
+            ((10, 15), (9, 18)),
+        ]));
+
+        assert_eq!(find_gap(&sensors, 11), Some(Coordinate::new(7, 6)));
+    }
+
+    proptest! {
+        #[test]
+        fn find_gap_matches_checking_every_position(report in small_report()) {
+            const BOUND: i64 = 20;
+            let sensors = sensors(&report);
+
+            let uncovered: HashSet<Coordinate> = (0..=BOUND)
+                .flat_map(|y| (0..=BOUND).map(move |x| Coordinate::new(x, y)))
+                .filter(|coord| !sensors.iter().any(|sensor| sensor.lies_in_coverage(coord)))
+                .collect();
+
+            match find_gap(&sensors, BOUND) {
+                Some(gap) => prop_assert!(uncovered.contains(&gap), "{gap:?} is covered"),
+                None => prop_assert!(uncovered.is_empty(), "missed {uncovered:?}"),
+            }
+        }
+
+        #[test]
+        fn parse_round_trips(report in inputs::day15()) {
+            let (sensors, beacons, _) = parse_sensors(&report.to_string()).unwrap();
+
+            let sensors: Vec<(i64, i64)> = sensors
+                .iter()
+                .map(|sensor| (sensor.coord.x, sensor.coord.y))
+                .collect();
+            let beacons: HashSet<(i64, i64)> = beacons
+                .iter()
+                .map(|beacon| (beacon.x, beacon.y))
+                .collect();
+
+            prop_assert_eq!(
+                sensors,
+                report.0.iter().map(|(sensor, _)| *sensor).collect::<Vec<_>>()
+            );
+            prop_assert_eq!(
+                beacons,
+                report.0.iter().map(|(_, beacon)| *beacon).collect::<HashSet<_>>()
+            );
+        }
+    }
+
+    // The map of the example in the puzzle text, which only shows the
+    // sensors and beacons
+    const EXAMPLE_MAP: &str = "\
+....S.......................
+......................S.....
+...............S............
+................SB..........
+............................
+............................
+............................
+..........S.......S.........
+............................
+............................
+....B.......................
+..S.........................
+............................
+............................
+..............S.......S.....
+B...........................
+...........SB...............
+................S..........B
+....S.......................
+............................
+............S......S........
+............................
+.......................B....";
+
+    #[test]
+    fn example_frame_matches_the_puzzle() {
+        let params = Params::parse("row_to_check = 10\nsearch_bound = 20").unwrap();
+        let report = Day15::parse_with(
+            include_str!("../../days/day15/example-input-day15"),
+            &params,
+        )
+        .unwrap();
+        let frame = report_frame(&report, find_gap(&report.sensors, report.search_bound));
+
+        let map: String = frame
+            .to_string()
+            .chars()
+            .map(|ch| match ch {
+                'S' | 'B' | '\n' => ch,
+                _ => '.',
+            })
+            .collect();
+        assert_eq!(map, EXAMPLE_MAP);
+
+        // Positions of the example are cells of their own
+        let checked_row: String = frame.row(10).collect();
+        assert_eq!(checked_row.matches('=').count(), 26);
+        // The gap is at x=14, y=11, the map starts at x=-2
+        assert_eq!(frame[Position::new(16, 11)], 'X');
+    }
+}
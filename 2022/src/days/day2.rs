@@ -0,0 +1,140 @@
+use aoc::{parse, solution::Solution, ParseError};
+
+fn get_game_score(opponent_choice: &str, your_choice: &str) -> usize {
+    match (opponent_choice, your_choice) {
+        ("A", "X") | ("B", "Y") | ("C", "Z") => 3,
+        ("A", "Y") | ("B", "Z") | ("C", "X") => 6,
+        ("A", "Z") | ("B", "X") | ("C", "Y") => 0,
+        _ => usize::MAX,
+    }
+}
+
+fn get_winning_move(opponent_choice: &str) -> &str {
+    match opponent_choice {
+        "A" => "Y",
+        "B" => "Z",
+        "C" => "X",
+        _ => "",
+    }
+}
+
+fn get_losing_move(opponent_choice: &str) -> &str {
+    match opponent_choice {
+        "A" => "Z",
+        "B" => "X",
+        "C" => "Y",
+        _ => "",
+    }
+}
+
+fn get_tie_move(opponent_choice: &str) -> &str {
+    match opponent_choice {
+        "A" => "X",
+        "B" => "Y",
+        "C" => "Z",
+        _ => "",
+    }
+}
+
+fn get_score_from_instr(instr: &str) -> usize {
+    match instr {
+        "X" => 0,
+        "Y" => 3,
+        "Z" => 6,
+        _ => usize::MAX,
+    }
+}
+
+fn get_move_score(your_choice: &str) -> usize {
+    match your_choice {
+        "X" => 1,
+        "Y" => 2,
+        "Z" => 3,
+        _ => usize::MAX,
+    }
+}
+
+fn get_move<'a, 'b: 'a>(desired_round_outcome: &'b str, opponent_choice: &'b str) -> &'a str {
+    match get_score_from_instr(desired_round_outcome) {
+        0 => get_losing_move(opponent_choice),
+        3 => get_tie_move(opponent_choice),
+        6 => get_winning_move(opponent_choice),
+        _ => "",
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    // Opponent's choice and the second column of the strategy guide,
+    // which is our move in part 1 and the desired outcome in part 2
+    type Input = Vec<(String, String)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim()
+            .split('\n')
+            .map(|x| {
+                let (opponent_choice, second_column) = parse::split_once(input, x.trim_end(), " ")?;
+
+                if !matches!(opponent_choice, "A" | "B" | "C") {
+                    return Err(ParseError::new(
+                        input,
+                        opponent_choice,
+                        "expected A, B or C",
+                    ));
+                }
+
+                if !matches!(second_column, "X" | "Y" | "Z") {
+                    return Err(ParseError::new(input, second_column, "expected X, Y or Z"));
+                }
+
+                Ok((opponent_choice.to_string(), second_column.to_string()))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input
+            .iter()
+            .map(|(opponent_choice, your_choice)| {
+                get_game_score(opponent_choice, your_choice) + get_move_score(your_choice)
+            })
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input
+            .iter()
+            .map(|(opponent_choice, desired_round_outcome)| {
+                get_score_from_instr(desired_round_outcome)
+                    + get_move_score(get_move(desired_round_outcome, opponent_choice))
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(guide in inputs::day2()) {
+            let rounds: Vec<(String, String)> = guide
+                .0
+                .iter()
+                .map(|(opponent_choice, second_column)| {
+                    (opponent_choice.to_string(), second_column.to_string())
+                })
+                .collect();
+
+            prop_assert_eq!(Day2::parse(&guide.to_string()).unwrap(), rounds);
+        }
+    }
+}
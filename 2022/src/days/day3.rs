@@ -0,0 +1,121 @@
+use aoc::{iter::IterExt, solution::Solution, ParseError};
+
+fn get_list_idx(char: char) -> usize {
+    if char.is_ascii_uppercase() {
+        return (char as usize) - 65;
+    } else if char.is_ascii_lowercase() {
+        return (char as usize) - 71;
+    }
+
+    usize::MAX
+}
+
+fn get_score(char: char) -> usize {
+    if char.is_ascii_uppercase() {
+        return (char as usize) - 38;
+    } else if char.is_ascii_lowercase() {
+        return (char as usize) - 96;
+    }
+
+    usize::MAX
+}
+
+fn find_overlap_char(first_str: &str, second_str: &str) -> char {
+    let mut chars_cnt = [0; 52];
+    first_str.chars().for_each(|ch| {
+        let chars_idx = get_list_idx(ch);
+        chars_cnt[chars_idx] += 1;
+    });
+
+    let overlap_char_opt = second_str.chars().find(|ch| {
+        let chars_idx = get_list_idx(*ch);
+        chars_cnt[chars_idx] > 0
+    });
+
+    overlap_char_opt.unwrap_or('\n')
+}
+
+fn find_badge_char(first_str: &str, second_str: &str, third_str: &str) -> char {
+    let mut chars_cnt = [(0, 0); 52];
+    first_str.chars().for_each(|ch| {
+        let chars_idx = get_list_idx(ch);
+        chars_cnt[chars_idx].0 += 1;
+    });
+
+    second_str.chars().for_each(|ch| {
+        let chars_idx = get_list_idx(ch);
+        if chars_cnt[chars_idx].0 > 0 {
+            chars_cnt[chars_idx].1 += 1;
+        }
+    });
+
+    let overlap_char_opt = third_str.chars().find(|ch| {
+        let chars_idx = get_list_idx(*ch);
+        chars_cnt[chars_idx].0 > 0 && chars_cnt[chars_idx].1 > 0
+    });
+
+    overlap_char_opt.unwrap_or('\n')
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    // One rucksack per line
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim()
+            .split('\n')
+            .map(|rucksack| {
+                match rucksack
+                    .char_indices()
+                    .find(|(_, ch)| !ch.is_ascii_alphabetic())
+                {
+                    Some((idx, ch)) => Err(ParseError::new(
+                        input,
+                        &rucksack[idx..idx + ch.len_utf8()],
+                        "expected an item, a-z or A-Z",
+                    )),
+                    None => Ok(rucksack.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input
+            .iter()
+            .map(|x| {
+                let str_len = x.len();
+                let (first_str, second_str) = x.split_at(str_len / 2);
+                get_score(find_overlap_char(first_str, second_str))
+            })
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input
+            .iter()
+            .chunks_of::<3>()
+            .map(|[first, second, third]| get_score(find_badge_char(first, second, third)))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(rucksacks in inputs::day3()) {
+            prop_assert_eq!(Day3::parse(&rucksacks.to_string()).unwrap(), rucksacks.0);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use aoc::{parse, solution::Solution, ParseError};
+
+#[derive(Debug)]
+pub struct PairAssignment {
+    first_lower_limit: u32,
+    first_upper_limit: u32,
+    second_lower_limit: u32,
+    second_upper_limit: u32,
+}
+
+impl PairAssignment {
+    fn either_contains_another(&self) -> bool {
+        contains_another(
+            self.first_lower_limit,
+            self.first_upper_limit,
+            self.second_lower_limit,
+            self.second_upper_limit,
+        ) || contains_another(
+            self.second_lower_limit,
+            self.second_upper_limit,
+            self.first_lower_limit,
+            self.first_upper_limit,
+        )
+    }
+}
+
+fn contains_another(x1: u32, x2: u32, y1: u32, y2: u32) -> bool {
+    x1 <= y1 && x2 >= y2
+}
+
+fn contains_overlap(x1: u32, x2: u32, y1: u32, y2: u32) -> bool {
+    if x1 > y1 {
+        y2 >= x1
+    } else if x1 < y1 {
+        y1 <= x2
+    } else {
+        true
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<PairAssignment>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim()
+            .split('\n')
+            .map(|pair_assignments| {
+                let (first_assignment, second_assignment) =
+                    parse::split_once(input, pair_assignments, ",")?;
+
+                let (first_lower_limit, first_upper_limit) =
+                    parse::split_once(input, first_assignment, "-")?;
+                let (second_lower_limit, second_upper_limit) =
+                    parse::split_once(input, second_assignment, "-")?;
+
+                Ok(PairAssignment {
+                    first_lower_limit: parse::number(input, first_lower_limit)?,
+                    first_upper_limit: parse::number(input, first_upper_limit)?,
+                    second_lower_limit: parse::number(input, second_lower_limit)?,
+                    second_upper_limit: parse::number(input, second_upper_limit)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input
+            .iter()
+            .filter(|pair| pair.either_contains_another())
+            .count()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input
+            .iter()
+            .filter(|pair| {
+                pair.either_contains_another()
+                    || contains_overlap(
+                        pair.first_lower_limit,
+                        pair.first_upper_limit,
+                        pair.second_lower_limit,
+                        pair.second_upper_limit,
+                    )
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(assignments in inputs::day4()) {
+            let parsed: Vec<[u32; 4]> = Day4::parse(&assignments.to_string())
+                .unwrap()
+                .iter()
+                .map(|pair| {
+                    [
+                        pair.first_lower_limit,
+                        pair.first_upper_limit,
+                        pair.second_lower_limit,
+                        pair.second_upper_limit,
+                    ]
+                })
+                .collect();
+
+            prop_assert_eq!(parsed, assignments.0);
+        }
+    }
+}
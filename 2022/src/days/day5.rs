@@ -0,0 +1,198 @@
+use aoc::{matrix::transpose, parse, solution::Solution, ParseError};
+
+#[derive(Debug)]
+pub struct Instr {
+    quantitiy: u32,
+    from: u32,
+    to: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Crate(char);
+
+#[derive(Debug)]
+pub struct Procedure {
+    crate_stacks: Vec<Vec<Crate>>,
+    instrs: Vec<Instr>,
+}
+
+// Every crate takes 3 characters, `[A]`, and is followed by a space
+fn parse_crate_row(input: &str, line: &str) -> Result<Vec<Option<Crate>>, ParseError> {
+    (0..line.len())
+        .step_by(4)
+        .map(|start| {
+            let cell = line
+                .get(start..(start + 3).min(line.len()))
+                .ok_or_else(|| ParseError::new(input, line, "expected crates like [A]"))?;
+
+            if cell.trim().is_empty() {
+                return Ok(None);
+            }
+
+            match cell.as_bytes() {
+                [b'[', krate, b']'] if krate.is_ascii_alphabetic() => {
+                    Ok(Some(Crate(*krate as char)))
+                }
+                _ => Err(ParseError::new(input, cell, "expected a crate like [A]")),
+            }
+        })
+        .collect()
+}
+
+fn parse_instr(input: &str, line: &str, stacks: usize) -> Result<Instr, ParseError> {
+    let invalid = || ParseError::new(input, line, "expected `move <N> from <N> to <N>`");
+
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let ["move", quantitiy, "from", from, "to", to] = words[..] else {
+        return Err(invalid());
+    };
+
+    let stack_number = |word: &str| -> Result<u32, ParseError> {
+        let number = parse::number::<u32>(input, word)?;
+        if number == 0 || number as usize > stacks {
+            return Err(ParseError::new(
+                input,
+                word,
+                format!("expected a stack between 1 and {stacks}"),
+            ));
+        }
+
+        Ok(number)
+    };
+
+    Ok(Instr {
+        quantitiy: parse::number(input, quantitiy)?,
+        from: stack_number(from)?,
+        to: stack_number(to)?,
+    })
+}
+
+// CrateMover 9000 moves one crate at a time
+fn move_crates_one_by_one(crate_stacks: &mut [Vec<Crate>], instr: &Instr) {
+    (0..instr.quantitiy).for_each(|_| {
+        let crate_to_be_moved = crate_stacks[(instr.from - 1) as usize].pop().unwrap();
+        crate_stacks[(instr.to - 1) as usize].push(crate_to_be_moved);
+    });
+}
+
+// CrateMover 9001 moves all crates of an instruction at once
+fn move_crates_batched(crate_stacks: &mut [Vec<Crate>], instr: &Instr) {
+    let mut batched_crates = vec![];
+    (0..instr.quantitiy).for_each(|_| {
+        let crate_to_be_moved = crate_stacks[(instr.from - 1) as usize].pop().unwrap();
+        batched_crates.push(crate_to_be_moved);
+    });
+
+    batched_crates.reverse();
+
+    for krate in batched_crates {
+        crate_stacks[(instr.to - 1) as usize].push(krate);
+    }
+}
+
+fn rearrange(procedure: &Procedure, move_crates: fn(&mut [Vec<Crate>], &Instr)) -> String {
+    let mut crate_stacks = procedure.crate_stacks.clone();
+
+    for instr in &procedure.instrs {
+        move_crates(&mut crate_stacks, instr);
+    }
+
+    crate_stacks
+        .iter()
+        .map(|crate_stack| crate_stack.last().unwrap().0)
+        .collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input_str = input.trim_end();
+
+        let split_idx = input_str.find("\n\n").unwrap_or_default();
+
+        let (crate_stacks, instrs_unprocessed) = input_str.split_at(split_idx);
+
+        // Prepocessing Crate Stacks
+        let mut lines_with_numbers: Vec<&str> = crate_stacks.split('\n').collect();
+        let numbers_line = lines_with_numbers.pop().unwrap_or_default();
+        let lines = lines_with_numbers;
+
+        let stacks = numbers_line.split_whitespace().count();
+
+        let mut crate_rows = vec![];
+        for line in lines {
+            let mut crate_row = parse_crate_row(input, line)?;
+            if crate_row.len() > stacks {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("expected at most {stacks} stacks"),
+                ));
+            }
+
+            // Trailing empty stacks may have been trimmed off
+            crate_row.resize(stacks, None);
+            crate_rows.push(crate_row);
+        }
+
+        // Columns of the drawing, bottom crate first
+        let crate_stacks = transpose(crate_rows)
+            .into_iter()
+            .map(|column| column.into_iter().rev().flatten().collect())
+            .collect();
+
+        // Prepocessing Instructions
+        let instrs = instrs_unprocessed
+            .trim()
+            .split('\n')
+            .map(|instr_unprocessed| parse_instr(input, instr_unprocessed, stacks))
+            .collect::<Result<Vec<Instr>, ParseError>>()?;
+
+        Ok(Procedure {
+            crate_stacks,
+            instrs,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        rearrange(input, move_crates_one_by_one)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        rearrange(input, move_crates_batched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(drawing in inputs::day5()) {
+            let procedure = Day5::parse(&drawing.to_string()).unwrap();
+
+            let stacks: Vec<Vec<char>> = procedure
+                .crate_stacks
+                .iter()
+                .map(|stack| stack.iter().map(|krate| krate.0).collect())
+                .collect();
+            let moves: Vec<[u32; 3]> = procedure
+                .instrs
+                .iter()
+                .map(|instr| [instr.quantitiy, instr.from, instr.to])
+                .collect();
+
+            prop_assert_eq!(stacks, drawing.stacks);
+            prop_assert_eq!(moves, drawing.moves);
+        }
+    }
+}
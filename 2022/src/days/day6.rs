@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use aoc::{solution::Solution, ParseError};
+
+const START_OF_PACKET_DISTINCT_CHARS: usize = 4;
+const START_OF_MESSAGE_DISTINCT_CHARS: usize = 14;
+
+fn find_marker_position(input_str: &str, distinct_chars_to_match: usize) -> usize {
+    let mut chars_iter = input_str.chars();
+    let mut sliding_buffer = vec![];
+
+    let mut idx_cnt = 0;
+    chars_iter.find(|ch| {
+        idx_cnt += 1;
+
+        sliding_buffer.push(*ch);
+
+        if sliding_buffer.len() == distinct_chars_to_match {
+            let set: HashSet<char> = HashSet::from_iter(sliding_buffer.clone());
+
+            if set.len() == distinct_chars_to_match {
+                return true;
+            }
+
+            sliding_buffer.remove(0);
+        }
+
+        false
+    });
+
+    idx_cnt
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    // The datastream buffer
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        find_marker_position(input, START_OF_PACKET_DISTINCT_CHARS)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        find_marker_position(input, START_OF_MESSAGE_DISTINCT_CHARS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(datastream in inputs::day6()) {
+            prop_assert_eq!(Day6::parse(&datastream.to_string()).unwrap(), datastream.0);
+        }
+    }
+}
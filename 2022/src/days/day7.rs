@@ -0,0 +1,388 @@
+use std::collections::HashMap;
+
+use aoc::{parse, solution::Solution, ParseError};
+
+#[derive(Debug)]
+struct DirInfo<'a> {
+    dir_size: u64,
+    outputs: Vec<Output<'a>>,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Output<'a> {
+    Dir { dir_name: &'a str },
+    File { size: u64 },
+}
+
+#[derive(Debug)]
+enum Command<'a> {
+    CD { dir_name: &'a str },
+    LS(Vec<Output<'a>>),
+}
+
+#[derive(Debug)]
+struct Parser<'a> {
+    // The whole terminal output, for error locations
+    input: &'a str,
+    lines: Vec<&'a str>,
+    idx: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str, lines: Vec<&'a str>) -> Self {
+        Parser {
+            input,
+            lines,
+            idx: 0,
+        }
+    }
+
+    fn parse_ls_cmd_output(&self, line: &'a str) -> Result<Output<'a>, ParseError> {
+        let (kind, name) = parse::split_once(self.input, line, " ")?;
+        match kind {
+            "dir" => Ok(Output::Dir { dir_name: name }),
+            file_size_str => Ok(Output::File {
+                size: parse::number(self.input, file_size_str)?,
+            }),
+        }
+    }
+
+    fn parse_command(&self, line: &'a str) -> Result<Command<'a>, ParseError> {
+        let split_command: Vec<&str> = line.split_ascii_whitespace().collect();
+
+        match split_command[..] {
+            ["$", "cd", dir_name] => Ok(Command::CD { dir_name }),
+            ["$", "ls"] => Ok(Command::LS(vec![])),
+            _ => Err(ParseError::new(
+                self.input,
+                line,
+                "expected `$ cd <dir>` or `$ ls`",
+            )),
+        }
+    }
+
+    fn parse(&mut self) -> Result<Vec<Command<'a>>, ParseError> {
+        let mut commands = vec![];
+
+        // The output of the last `$ ls`, until the next command. An empty
+        // directory lists nothing but still has to be recorded
+        let mut output_lines: Option<Vec<Output>> = None;
+
+        while let Some(line) = self.advance() {
+            if line.starts_with('$') {
+                if let Some(output_lines) = output_lines.take() {
+                    commands.push(Command::LS(output_lines));
+                }
+
+                match self.parse_command(line)? {
+                    Command::LS(_) => output_lines = Some(vec![]),
+                    cmd => commands.push(cmd),
+                }
+            } else {
+                let Some(output_lines) = output_lines.as_mut() else {
+                    return Err(ParseError::new(
+                        self.input,
+                        line,
+                        "expected a command, output has to follow `$ ls`",
+                    ));
+                };
+                output_lines.push(self.parse_ls_cmd_output(line)?);
+            }
+        }
+
+        if let Some(output_lines) = output_lines {
+            commands.push(Command::LS(output_lines));
+        }
+
+        Ok(commands)
+    }
+
+    fn advance(&mut self) -> Option<&'a str> {
+        let ele: Option<&str> = self.lines.get(self.idx).copied();
+        self.idx += 1;
+        ele
+    }
+}
+
+struct Stack {
+    data: Vec<String>,
+    size: usize,
+}
+
+impl Stack {
+    fn new() -> Self {
+        Self {
+            size: 0,
+            data: vec![],
+        }
+    }
+
+    fn push(&mut self, name: String) {
+        self.data.push(name);
+        self.size += 1;
+    }
+
+    fn pop(&mut self) -> String {
+        self.size -= 1;
+        self.data.remove(self.size)
+    }
+
+    // fn peek(&mut self) -> Option<&'a str> {
+    //     self.data.last().copied()
+    // }
+
+    fn construct_path(&self) -> String {
+        if self.size > 1 {
+            "/".to_string() + &self.data[1..].join("/")
+        } else {
+            "-".to_string()
+        }
+    }
+}
+
+fn calculate_dir_size(
+    input: &str,
+    outputs: &[Output],
+    dir_infos: &HashMap<String, DirInfo>,
+    curr_dir_name: String,
+) -> Result<u64, ParseError> {
+    let mut dir_size = 0;
+
+    for output in outputs {
+        match output {
+            Output::File { size } => {
+                dir_size += size;
+            }
+            Output::Dir { dir_name } => {
+                let path = if curr_dir_name == "-" {
+                    "/".to_string() + dir_name
+                } else {
+                    curr_dir_name.clone() + "/" + dir_name
+                };
+
+                let size = dir_infos
+                    .get(&path)
+                    .ok_or_else(|| {
+                        ParseError::new(input, dir_name, "directory is never listed with `$ ls`")
+                    })?
+                    .dir_size;
+                dir_size += size;
+            }
+        }
+    }
+
+    Ok(dir_size)
+}
+
+fn calculate_dir_sizes(input_str: &str) -> Result<HashMap<String, u64>, ParseError> {
+    let lines: Vec<&str> = input_str.trim().split('\n').collect();
+
+    let mut parser = Parser::new(input_str, lines);
+    let cmds = parser.parse()?;
+
+    // Here we make an assumption that no files/dirs will have same
+    // name
+    //
+    // Fk this assumption, it costed my so many debugging hours
+    let mut dir_infos: HashMap<String, DirInfo> = HashMap::new();
+
+    let mut stack = Stack::new();
+
+    for cmd in cmds {
+        match cmd {
+            Command::CD { dir_name } => {
+                if dir_name == ".." {
+                    if stack.size == 0 {
+                        return Err(ParseError::new(
+                            input_str,
+                            dir_name,
+                            "cannot leave the outermost directory",
+                        ));
+                    }
+
+                    let path = stack.construct_path();
+
+                    match dir_infos.get(&path) {
+                        Some(dir_info) => {
+                            if dir_info.dir_size == u64::MAX {
+                                let dir_size = calculate_dir_size(
+                                    input_str,
+                                    &dir_info.outputs,
+                                    &dir_infos,
+                                    path.clone(),
+                                )?;
+                                // update it in hashmap
+                                dir_infos.insert(
+                                    path,
+                                    DirInfo {
+                                        dir_size,
+                                        outputs: dir_info.outputs.clone(),
+                                    },
+                                );
+                            }
+                        }
+                        None => {
+                            return Err(ParseError::new(
+                                input_str,
+                                dir_name,
+                                "directory is left before being listed with `$ ls`",
+                            ));
+                        }
+                    }
+                    stack.pop();
+                } else {
+                    stack.push(dir_name.to_string());
+                }
+            }
+            Command::LS(outputs) => {
+                // This also covers the case where there are no files in a dir
+                let only_files = outputs.iter().all(|x| {
+                    if let Output::Dir { dir_name: _ } = x {
+                        return false;
+                    }
+
+                    true
+                });
+
+                let path = stack.construct_path();
+
+                if only_files {
+                    let dir_size =
+                        calculate_dir_size(input_str, &outputs, &dir_infos, path.clone())?;
+                    dir_infos.insert(path, DirInfo { dir_size, outputs });
+                } else {
+                    dir_infos.insert(
+                        path,
+                        DirInfo {
+                            dir_size: u64::MAX,
+                            outputs,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    while stack.size > 0 {
+        let path = stack.construct_path();
+        let _name = stack.pop();
+
+        // Directories which are entered but never listed have no size yet
+        let Some(dir_info) = dir_infos.get(&path) else {
+            return Err(ParseError::without_location(format!(
+                "directory {path:?} is never listed with `$ ls`"
+            )));
+        };
+
+        let dir_size = calculate_dir_size(input_str, &dir_info.outputs, &dir_infos, path.clone())?;
+        dir_infos.insert(
+            path,
+            DirInfo {
+                dir_size,
+                outputs: dir_info.outputs.clone(),
+            },
+        );
+    }
+
+    Ok(dir_infos
+        .into_iter()
+        .map(|(path, dir_info)| (path, dir_info.dir_size))
+        .collect())
+}
+
+const DISK_SIZE: u64 = 70000000;
+// Free space the update needs
+const NEEDED_SPACE: u64 = 30000000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    // Total size of every directory, keyed by its path
+    type Input = HashMap<String, u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let dir_sizes = calculate_dir_sizes(input)?;
+
+        match dir_sizes.get("-") {
+            None => Err(ParseError::without_location(
+                "expected the outermost directory to be listed with `$ ls`",
+            )),
+            Some(&root_dir_size) if root_dir_size > DISK_SIZE => {
+                Err(ParseError::without_location(format!(
+                    "the files take {root_dir_size} bytes, more than the {DISK_SIZE} of the disk"
+                )))
+            }
+            Some(_) => Ok(dir_sizes),
+        }
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let mut total: u64 = 0;
+
+        for dir_size in input.values() {
+            if *dir_size <= 100000 {
+                total += dir_size;
+            }
+        }
+
+        total
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        let root_dir_size = input
+            .get("-")
+            .expect("the outermost directory is checked while parsing");
+
+        // Both checked while parsing not to be larger than the disk
+        let unused_space = DISK_SIZE - root_dir_size;
+        let offset_from_goal = NEEDED_SPACE.saturating_sub(unused_space);
+        // Nothing has to be deleted
+        if offset_from_goal == 0 {
+            return 0;
+        }
+
+        let mut min: u64 = u64::MAX;
+
+        for dir_size in input.values() {
+            if *dir_size >= offset_from_goal && *dir_size < min {
+                min = *dir_size;
+            }
+        }
+
+        min
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(filesystem in inputs::day7()) {
+            prop_assert_eq!(Day7::parse(&filesystem.to_string()).unwrap(), filesystem.sizes());
+        }
+    }
+
+    #[test]
+    fn disk_with_enough_free_space_needs_no_deletion() {
+        let dir_sizes = Day7::parse("$ cd /\n$ ls\n1000 a.txt\n").unwrap();
+
+        assert_eq!(Day7::part2(&dir_sizes), 0);
+    }
+
+    #[test]
+    fn files_larger_than_the_disk_are_rejected() {
+        let err = Day7::parse("$ cd /\n$ ls\n70000001 big.bin\n").unwrap_err();
+
+        assert_eq!(
+            err.message,
+            "the files take 70000001 bytes, more than the 70000000 of the disk"
+        );
+    }
+}
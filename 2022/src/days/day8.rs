@@ -0,0 +1,85 @@
+use aoc::{
+    grid::{Direction, Position},
+    parse,
+    solution::Solution,
+    Grid, ParseError,
+};
+
+// A tree is visible from outside the grid if every tree between it and an
+// edge is shorter
+fn is_visible(heights: &Grid<u32>, pos: Position) -> bool {
+    let height = heights[pos];
+
+    Direction::ALL.into_iter().any(|direction| {
+        heights
+            .ray(pos, direction.step())
+            .all(|other| heights[other] < height)
+    })
+}
+
+// Product of the viewing distances in every direction, a viewing distance
+// stops at the edge or at the first tree at least as tall as this one
+fn scenic_score(heights: &Grid<u32>, pos: Position) -> usize {
+    let height = heights[pos];
+
+    Direction::ALL
+        .into_iter()
+        .map(|direction| {
+            let mut viewing_distance = 0;
+            for other in heights.ray(pos, direction.step()) {
+                viewing_distance += 1;
+                if heights[other] >= height {
+                    break;
+                }
+            }
+
+            viewing_distance
+        })
+        .product()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    // Tree heights, one row per line
+    type Input = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::grid(input, "expected a tree height, 0-9", |ch| ch.to_digit(10))
+    }
+
+    fn part1(heights: &Self::Input) -> Self::Answer1 {
+        heights
+            .positions()
+            .filter(|&pos| is_visible(heights, pos))
+            .count()
+    }
+
+    fn part2(heights: &Self::Input) -> Self::Answer2 {
+        heights
+            .positions()
+            .map(|pos| scenic_score(heights, pos))
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(heights in inputs::day8()) {
+            prop_assert_eq!(
+                Day8::parse(&heights.to_string()).unwrap(),
+                Grid::from_rows(heights.0).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use aoc::{
+    parse,
+    solution::Solution,
+    visualize::{self, Frame},
+    ParseError, SparseGrid,
+};
+
+type Coordinate = aoc::Coordinate<i32>;
+
+#[derive(Debug)]
+pub struct Instr {
+    // One step of the head towards the direction of the instruction
+    offset: Coordinate,
+    steps: usize,
+}
+
+fn get_offset_from_current_coordinate(direction: &str) -> Option<Coordinate> {
+    match direction {
+        "R" => Some(Coordinate { x: 1, y: 0 }),
+        "L" => Some(Coordinate { x: -1, y: 0 }),
+        "U" => Some(Coordinate { x: 0, y: 1 }),
+        "D" => Some(Coordinate { x: 0, y: -1 }),
+        _ => None,
+    }
+}
+
+// The head is drawn as 0 and the start as s
+fn rope_frame<const TOTAL_KNOTS: usize>(curr_coords: [Coordinate; TOTAL_KNOTS]) -> Frame {
+    let mut grid = SparseGrid::new();
+    grid.insert(aoc::Coordinate::new(0, 0), 's');
+
+    // Tail first, so knots in front are drawn over the ones behind them
+    for (i, coord) in curr_coords.iter().enumerate().rev() {
+        let knot = char::from_digit(i as u32, 36).unwrap();
+        // Rows grow downwards while "U" moves towards positive y
+        grid.insert(
+            aoc::Coordinate::new(coord.x.into(), -i64::from(coord.y)),
+            knot,
+        );
+    }
+
+    grid.to_grid(|cell| cell.copied().unwrap_or('.'))
+}
+
+// Returns the number of positions visited by the tail
+fn simulate_rope<const TOTAL_KNOTS: usize>(instrs: &[Instr]) -> usize {
+    let mut positions_visited: HashSet<Coordinate> = HashSet::new();
+    positions_visited.insert(Coordinate { x: 0, y: 0 });
+
+    let mut curr_coords = [Coordinate { x: 0, y: 0 }; TOTAL_KNOTS];
+
+    instrs.iter().for_each(|instr| {
+        for _step in 0..instr.steps {
+            // update head
+            curr_coords[0] = curr_coords[0] + instr.offset;
+
+            // update remaining knots
+            for i in 1..TOTAL_KNOTS {
+                let dx = curr_coords[i - 1].x - curr_coords[i].x;
+                let dy = curr_coords[i - 1].y - curr_coords[i].y;
+
+                if (dx.abs() >= 2 || dy.abs() >= 2) && (dx.abs() > 1 || dy.abs() > 1) {
+                    curr_coords[i].x += dx.signum();
+                    curr_coords[i].y += dy.signum();
+                }
+            }
+
+            positions_visited.insert(curr_coords[TOTAL_KNOTS - 1]);
+        }
+        visualize::push(|| rope_frame(curr_coords));
+    });
+
+    positions_visited.len()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Instr>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim()
+            .split('\n')
+            .map(|line| {
+                let (direction, steps) = parse::split_once(input, line, " ")?;
+                let offset = get_offset_from_current_coordinate(direction).ok_or_else(|| {
+                    ParseError::new(input, direction, "expected a direction, R, L, U or D")
+                })?;
+
+                Ok(Instr {
+                    offset,
+                    steps: parse::number(input, steps)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        simulate_rope::<2>(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        simulate_rope::<10>(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::days::inputs;
+
+    proptest! {
+        #[test]
+        fn parse_round_trips(motions in inputs::day9()) {
+            let parsed: Vec<(Option<Coordinate>, usize)> = Day9::parse(&motions.to_string())
+                .unwrap()
+                .iter()
+                .map(|instr| (Some(instr.offset), instr.steps))
+                .collect();
+            let expected: Vec<(Option<Coordinate>, usize)> = motions
+                .0
+                .iter()
+                .map(|(direction, steps)| {
+                    (get_offset_from_current_coordinate(&direction.to_string()), *steps)
+                })
+                .collect();
+
+            prop_assert_eq!(parsed, expected);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Runs every day on its `example-input-dayN` file and compares the
+//! answers with the example answers recorded in answers.txt.
+
+use std::fs;
+
+use aoc::{
+    answers::{self, InputKind},
+    input::{self, InputSource},
+    params,
+    solution::Part,
+    store::InputStore,
+};
+
+use crate::YEAR;
+
+fn check_example(day: u8, part: Part) {
+    let expected = answers::load_manifest(&YEAR)
+        .unwrap()
+        .into_iter()
+        .find(|recorded| {
+            recorded.day == day && recorded.part == part && recorded.kind == InputKind::Example
+        })
+        .unwrap_or_else(|| {
+            panic!(
+                "no example answer recorded for day {day} part {}",
+                part.number()
+            )
+        });
+
+    // A day whose example answer is still missing is not done yet
+    assert!(
+        !expected.is_pending(),
+        "day {day} part {} has no example answer yet, record it in answers.txt",
+        part.number()
+    );
+
+    let store = InputStore::offline(InputStore::default_cache_dir());
+    let input = input::load(&store, &YEAR, day, &InputSource::Example).unwrap();
+    let params = params::load(&YEAR, day, &InputSource::Example).unwrap();
+    let result = (YEAR.find(day).unwrap().solve)(&input, &params, &[part]).unwrap();
+
+    assert_eq!(result.parts[0].answer.to_string(), expected.answer);
+}
+
+macro_rules! example_tests {
+    ($($name:ident: $day:expr),* $(,)?) => {
+        const CHECKED_EXAMPLES: &[u8] = &[$($day),*];
+
+        $(
+            mod $name {
+                use aoc::solution::Part;
+
+                #[test]
+                fn part1() {
+                    super::check_example($day, Part::One);
+                }
+
+                #[test]
+                fn part2() {
+                    super::check_example($day, Part::Two);
+                }
+            }
+        )*
+    };
+}
+
+example_tests! {
+    day1: 1,
+    day2: 2,
+    day3: 3,
+    day4: 4,
+    day5: 5,
+    day6: 6,
+    day7: 7,
+    day8: 8,
+    day9: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+    day13: 13,
+    day14: 14,
+    day15: 15,
+}
+
+#[test]
+fn every_example_file_is_checked() {
+    for entry in fs::read_dir(input::days_dir(&YEAR)).unwrap() {
+        let path = entry.unwrap().path();
+        let Some(day) = path
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse::<u8>().ok())
+        else {
+            continue;
+        };
+
+        if !input::example_input_path(&YEAR, day).exists() {
+            continue;
+        }
+
+        assert!(
+            CHECKED_EXAMPLES.contains(&day),
+            "{} has no example test",
+            input::example_input_path(&YEAR, day).display()
+        );
+    }
+}
@@ -0,0 +1,574 @@
+//! Random puzzle inputs for property tests. Every day has a strategy
+//! generating a model of its input, whose `Display` writes it out in the
+//! format of the puzzle.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
+
+use proptest::{collection::vec, prelude::*, sample::select};
+
+// Writes `items` with `separator` between every two of them
+fn write_separated<T: Display>(
+    f: &mut fmt::Formatter<'_>,
+    items: impl IntoIterator<Item = T>,
+    separator: &str,
+) -> fmt::Result {
+    for (idx, item) in items.into_iter().enumerate() {
+        if idx > 0 {
+            f.write_str(separator)?;
+        }
+        write!(f, "{item}")?;
+    }
+
+    Ok(())
+}
+
+/// The items carried by every elf.
+#[derive(Debug, Clone)]
+pub struct Calories(pub Vec<Vec<u32>>);
+
+impl Display for Calories {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, items) in self.0.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for item in items {
+                writeln!(f, "{item}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn day1() -> impl Strategy<Value = Calories> {
+    vec(vec(1..100_000u32, 1..8), 1..20).prop_map(Calories)
+}
+
+/// The opponent's choice and the second column of every round.
+#[derive(Debug, Clone)]
+pub struct StrategyGuide(pub Vec<(char, char)>);
+
+impl Display for StrategyGuide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (opponent_choice, second_column) in &self.0 {
+            writeln!(f, "{opponent_choice} {second_column}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn day2() -> impl Strategy<Value = StrategyGuide> {
+    vec(
+        (select(vec!['A', 'B', 'C']), select(vec!['X', 'Y', 'Z'])),
+        1..50,
+    )
+    .prop_map(StrategyGuide)
+}
+
+#[derive(Debug, Clone)]
+pub struct Rucksacks(pub Vec<String>);
+
+impl Display for Rucksacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for rucksack in &self.0 {
+            writeln!(f, "{rucksack}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn day3() -> impl Strategy<Value = Rucksacks> {
+    vec("[a-zA-Z]{2,40}", 1..30).prop_map(Rucksacks)
+}
+
+/// The section ranges of both elves of every pair, lower limit first.
+#[derive(Debug, Clone)]
+pub struct Assignments(pub Vec<[u32; 4]>);
+
+impl Display for Assignments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for [first_lower, first_upper, second_lower, second_upper] in &self.0 {
+            writeln!(
+                f,
+                "{first_lower}-{first_upper},{second_lower}-{second_upper}"
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn section_range() -> impl Strategy<Value = (u32, u32)> {
+    (1..100u32, 0..20u32).prop_map(|(lower, len)| (lower, lower + len))
+}
+
+pub fn day4() -> impl Strategy<Value = Assignments> {
+    vec((section_range(), section_range()), 1..30)
+        .prop_map(|pairs| {
+            pairs
+                .into_iter()
+                .map(
+                    |((first_lower, first_upper), (second_lower, second_upper))| {
+                        [first_lower, first_upper, second_lower, second_upper]
+                    },
+                )
+                .collect()
+        })
+        .prop_map(Assignments)
+}
+
+/// The stacks of crates, bottom crate first, and the moves of the
+/// procedure as quantity, from and to.
+#[derive(Debug, Clone)]
+pub struct Drawing {
+    pub stacks: Vec<Vec<char>>,
+    pub moves: Vec<[u32; 3]>,
+}
+
+impl Display for Drawing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row = self.stacks.iter().map(|stack| match stack.get(level) {
+                Some(krate) => format!("[{krate}]"),
+                None => "   ".to_string(),
+            });
+            write_separated(f, row, " ")?;
+            writeln!(f)?;
+        }
+
+        write_separated(
+            f,
+            (1..=self.stacks.len()).map(|number| format!(" {number} ")),
+            " ",
+        )?;
+        writeln!(f, "\n")?;
+
+        for [quantity, from, to] in &self.moves {
+            writeln!(f, "move {quantity} from {from} to {to}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn day5() -> impl Strategy<Value = Drawing> {
+    (1..=9u32)
+        .prop_flat_map(|stacks| {
+            (
+                vec(vec(proptest::char::range('A', 'Z'), 0..8), stacks as usize),
+                vec((1..6u32, 1..=stacks, 1..=stacks), 1..20),
+            )
+        })
+        // The drawing has at least one crate
+        .prop_filter("no crates", |(stacks, _)| {
+            stacks.iter().any(|stack| !stack.is_empty())
+        })
+        .prop_map(|(stacks, moves)| Drawing {
+            stacks,
+            moves: moves
+                .into_iter()
+                .map(|(quantity, from, to)| [quantity, from, to])
+                .collect(),
+        })
+}
+
+#[derive(Debug, Clone)]
+pub struct Datastream(pub String);
+
+impl Display for Datastream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.0)
+    }
+}
+
+pub fn day6() -> impl Strategy<Value = Datastream> {
+    "[a-z]{1,100}".prop_map(Datastream)
+}
+
+/// A directory with its files and subdirectories, written out as the
+/// terminal session exploring it depth first.
+#[derive(Debug, Clone)]
+pub struct Filesystem {
+    pub files: Vec<(String, u64)>,
+    pub dirs: Vec<(String, Filesystem)>,
+}
+
+impl Filesystem {
+    pub fn total_size(&self) -> u64 {
+        let files: u64 = self.files.iter().map(|(_, size)| size).sum();
+        let dirs: u64 = self.dirs.iter().map(|(_, dir)| dir.total_size()).sum();
+        files + dirs
+    }
+
+    /// The total size of every directory keyed by its path, `-` for the
+    /// outermost one and `/a/b` for the others.
+    pub fn sizes(&self) -> HashMap<String, u64> {
+        fn collect(dir: &Filesystem, path: &str, sizes: &mut HashMap<String, u64>) {
+            sizes.insert(
+                if path.is_empty() { "-" } else { path }.to_string(),
+                dir.total_size(),
+            );
+            for (name, subdir) in &dir.dirs {
+                collect(subdir, &format!("{path}/{name}"), sizes);
+            }
+        }
+
+        let mut sizes = HashMap::new();
+        collect(self, "", &mut sizes);
+        sizes
+    }
+
+    fn write_session(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "$ ls")?;
+        for (name, _) in &self.dirs {
+            writeln!(f, "dir {name}")?;
+        }
+        for (name, size) in &self.files {
+            writeln!(f, "{size} {name}")?;
+        }
+
+        for (name, dir) in &self.dirs {
+            writeln!(f, "$ cd {name}")?;
+            dir.write_session(f)?;
+            writeln!(f, "$ cd ..")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Filesystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "$ cd /")?;
+        self.write_session(f)
+    }
+}
+
+fn files() -> impl Strategy<Value = Vec<(String, u64)>> {
+    vec(("[a-z]{1,8}(\\.[a-z]{1,3})?", 1..300_000u64), 0..5)
+}
+
+pub fn day7() -> impl Strategy<Value = Filesystem> {
+    let leaf = files().prop_map(|files| Filesystem {
+        files,
+        dirs: vec![],
+    });
+
+    leaf.prop_recursive(4, 32, 4, |subdir| {
+        (
+            files(),
+            // Names of subdirectories are unique within their directory
+            proptest::collection::btree_map("[a-z]{1,6}", subdir, 0..4),
+        )
+            .prop_map(|(files, dirs)| Filesystem {
+                files,
+                dirs: dirs.into_iter().collect(),
+            })
+    })
+}
+
+/// The height of every tree, row by row.
+#[derive(Debug, Clone)]
+pub struct TreeHeights(pub Vec<Vec<u32>>);
+
+impl Display for TreeHeights {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.0 {
+            write_separated(f, row, "")?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn day8() -> impl Strategy<Value = TreeHeights> {
+    (1..10usize, 1..10usize)
+        .prop_flat_map(|(rows, cols)| vec(vec(0..=9u32, cols), rows))
+        .prop_map(TreeHeights)
+}
+
+/// The direction and number of steps of every motion of the head.
+#[derive(Debug, Clone)]
+pub struct Motions(pub Vec<(char, usize)>);
+
+impl Display for Motions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (direction, steps) in &self.0 {
+            writeln!(f, "{direction} {steps}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn day9() -> impl Strategy<Value = Motions> {
+    vec((select(vec!['R', 'L', 'U', 'D']), 1..20usize), 1..40).prop_map(Motions)
+}
+
+/// Every instruction of the program, `None` for `noop`.
+#[derive(Debug, Clone)]
+pub struct Program(pub Vec<Option<i32>>);
+
+impl Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for instruction in &self.0 {
+            match instruction {
+                Some(x) => writeln!(f, "addx {x}")?,
+                None => writeln!(f, "noop")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn day10() -> impl Strategy<Value = Program> {
+    vec(prop::option::of(-40..40i32), 1..150).prop_map(Program)
+}
+
+#[derive(Debug, Clone)]
+pub struct MonkeyNotes {
+    pub items: Vec<u128>,
+    pub operator: char,
+    // `None` for `old`
+    pub operand: Option<u128>,
+    pub divisor: u128,
+    pub if_true: usize,
+    pub if_false: usize,
+}
+
+impl MonkeyNotes {
+    pub fn operation(&self, old: u128) -> u128 {
+        let operand = self.operand.unwrap_or(old);
+        match self.operator {
+            '+' => old + operand,
+            _ => old * operand,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Notes(pub Vec<MonkeyNotes>);
+
+impl Display for Notes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, monkey) in self.0.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "Monkey {idx}:")?;
+            write!(f, "  Starting items: ")?;
+            write_separated(f, &monkey.items, ", ")?;
+            writeln!(f)?;
+            match monkey.operand {
+                Some(operand) => {
+                    writeln!(f, "  Operation: new = old {} {operand}", monkey.operator)?
+                }
+                None => writeln!(f, "  Operation: new = old {} old", monkey.operator)?,
+            }
+            writeln!(f, "  Test: divisible by {}", monkey.divisor)?;
+            writeln!(f, "    If true: throw to monkey {}", monkey.if_true)?;
+            writeln!(f, "    If false: throw to monkey {}", monkey.if_false)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn day11() -> impl Strategy<Value = Notes> {
+    (2..8usize)
+        .prop_flat_map(|monkeys| {
+            // Monkeys never throw to themselves
+            let other_monkey = move |idx: usize| {
+                (0..monkeys - 1).prop_map(move |other| if other >= idx { other + 1 } else { other })
+            };
+
+            (0..monkeys)
+                .map(|idx| {
+                    (
+                        vec(1..100u128, 1..6),
+                        select(vec!['+', '*']),
+                        prop::option::of(1..20u128),
+                        select(vec![2u128, 3, 5, 7, 11, 13, 17, 19, 23]),
+                        other_monkey(idx),
+                        other_monkey(idx),
+                    )
+                        .prop_map(
+                            |(items, operator, operand, divisor, if_true, if_false)| MonkeyNotes {
+                                items,
+                                operator,
+                                operand,
+                                divisor,
+                                if_true,
+                                if_false,
+                            },
+                        )
+                })
+                .collect::<Vec<_>>()
+        })
+        .prop_map(Notes)
+}
+
+/// Heights a-z with a single start `S` and end `E`.
+#[derive(Debug, Clone)]
+pub struct Heightmap(pub Vec<Vec<char>>);
+
+impl Display for Heightmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.0 {
+            write_separated(f, row, "")?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn day12() -> impl Strategy<Value = Heightmap> {
+    (1..8usize, 2..10usize)
+        .prop_flat_map(|(rows, cols)| {
+            (
+                vec(vec(proptest::char::range('a', 'z'), cols), rows),
+                0..rows * cols,
+                1..rows * cols,
+            )
+        })
+        .prop_map(|(mut heights, start, end_offset)| {
+            let cols = heights[0].len();
+            let end = (start + end_offset) % (heights.len() * cols);
+            heights[start / cols][start % cols] = 'S';
+            heights[end / cols][end % cols] = 'E';
+            Heightmap(heights)
+        })
+}
+
+/// Packets written as text, e.g. `[1,[2,3]]`, two per pair.
+#[derive(Debug, Clone)]
+pub struct Packets(pub Vec<(String, String)>);
+
+impl Display for Packets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (left, right)) in self.0.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{left}\n{right}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn packet() -> impl Strategy<Value = String> {
+    let integer = (0..=10u32).prop_map(|integer| integer.to_string());
+
+    let value = integer.prop_recursive(4, 24, 5, |value| {
+        vec(value, 0..5).prop_map(|values| format!("[{}]", values.join(",")))
+    });
+    // Packets are always lists
+    vec(value, 0..5).prop_map(|values| format!("[{}]", values.join(",")))
+}
+
+pub fn day13() -> impl Strategy<Value = Packets> {
+    vec((packet(), packet()), 1..10).prop_map(Packets)
+}
+
+/// The corners of every rock path.
+#[derive(Debug, Clone)]
+pub struct Scan(pub Vec<Vec<(i64, i64)>>);
+
+impl Scan {
+    /// Every rock on the paths, including the ones between corners.
+    pub fn rocks(&self) -> HashSet<(i64, i64)> {
+        let mut rocks = HashSet::new();
+        for path in &self.0 {
+            rocks.insert(path[0]);
+            for corners in path.windows(2) {
+                let ((x1, y1), (x2, y2)) = (corners[0], corners[1]);
+                for x in x1.min(x2)..=x1.max(x2) {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        rocks.insert((x, y));
+                    }
+                }
+            }
+        }
+
+        rocks
+    }
+}
+
+impl Display for Scan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for path in &self.0 {
+            write_separated(f, path.iter().map(|(x, y)| format!("{x},{y}")), " -> ")?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn rock_path() -> impl Strategy<Value = Vec<(i64, i64)>> {
+    (
+        (470..530i64, 30..60i64),
+        vec((1..10i64, any::<bool>()), 0..6),
+    )
+        .prop_map(|(start, segments)| {
+            // Segments alternate between horizontal and vertical, and never
+            // reach above the sand source
+            let mut corners = vec![start];
+            for (idx, (len, backwards)) in segments.into_iter().enumerate() {
+                let (x, y) = *corners.last().unwrap();
+                let len = if backwards { -len } else { len };
+                corners.push(if idx % 2 == 0 {
+                    (x + len, y)
+                } else {
+                    (x, y + len)
+                });
+            }
+
+            corners
+        })
+}
+
+pub fn day14() -> impl Strategy<Value = Scan> {
+    vec(rock_path(), 1..10).prop_map(Scan)
+}
+
+/// Every sensor and the beacon closest to it.
+#[derive(Debug, Clone)]
+pub struct SensorReport(pub Vec<((i64, i64), (i64, i64))>);
+
+impl Display for SensorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((sensor_x, sensor_y), (beacon_x, beacon_y)) in &self.0 {
+            writeln!(
+                f,
+                "Sensor at x={sensor_x}, y={sensor_y}: closest beacon is at x={beacon_x}, y={beacon_y}"
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn day15() -> impl Strategy<Value = SensorReport> {
+    let position = || (-1_000..5_000i64, -1_000..5_000i64);
+
+    vec((position(), (-500..500i64, -500..500i64)), 1..20)
+        .prop_map(|sensors| {
+            sensors
+                .into_iter()
+                .map(|((x, y), (dx, dy))| ((x, y), (x + dx, y + dy)))
+                .collect()
+        })
+        .prop_map(SensorReport)
+}
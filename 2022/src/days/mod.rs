@@ -0,0 +1,87 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use aoc::{solution::solve, Day};
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        solve: solve::<day1::Day1>,
+    },
+    Day {
+        day: 2,
+        solve: solve::<day2::Day2>,
+    },
+    Day {
+        day: 3,
+        solve: solve::<day3::Day3>,
+    },
+    Day {
+        day: 4,
+        solve: solve::<day4::Day4>,
+    },
+    Day {
+        day: 5,
+        solve: solve::<day5::Day5>,
+    },
+    Day {
+        day: 6,
+        solve: solve::<day6::Day6>,
+    },
+    Day {
+        day: 7,
+        solve: solve::<day7::Day7>,
+    },
+    Day {
+        day: 8,
+        solve: solve::<day8::Day8>,
+    },
+    Day {
+        day: 9,
+        solve: solve::<day9::Day9>,
+    },
+    Day {
+        day: 10,
+        solve: solve::<day10::Day10>,
+    },
+    Day {
+        day: 11,
+        solve: solve::<day11::Day11>,
+    },
+    Day {
+        day: 12,
+        solve: solve::<day12::Day12>,
+    },
+    Day {
+        day: 13,
+        solve: solve::<day13::Day13>,
+    },
+    Day {
+        day: 14,
+        solve: solve::<day14::Day14>,
+    },
+    Day {
+        day: 15,
+        solve: solve::<day15::Day15>,
+    },
+];
+
+#[cfg(test)]
+mod examples;
+#[cfg(test)]
+pub(crate) mod inputs;
+#[cfg(test)]
+mod parsers;
@@ -0,0 +1,126 @@
+//! Properties every parser has to hold: generated puzzle inputs parse, and
+//! no input, however damaged, makes a parser panic. The round trips of the
+//! parsed values are checked next to each day.
+
+use aoc::{input::InputSource, params, solution::DayResult, ParseError};
+use proptest::{collection::vec, prelude::*, sample::select};
+
+use crate::YEAR;
+
+// Runs only the parse stage of `day`, with the puzzle parameters of its
+// example
+fn parse(day: u8, input: &str) -> Result<DayResult, ParseError> {
+    let params = params::load(&YEAR, day, &InputSource::Example).unwrap();
+    (YEAR.find(day).unwrap().solve)(input, &params, &[])
+}
+
+// Characters the puzzle formats are built from, inserting them is more
+// likely to reach deep into a parser than arbitrary text
+const FORMAT_CHARS: &[char] = &[
+    '0', '1', '9', '-', ' ', '\n', ',', ':', '=', '[', ']', '$', '.', '/', 'a', 'A', 'x', 'S', 'E',
+];
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Delete,
+    Insert(char),
+    Truncate,
+    DuplicateLine,
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    prop_oneof![
+        Just(Edit::Delete),
+        select(FORMAT_CHARS).prop_map(Edit::Insert),
+        Just(Edit::Truncate),
+        Just(Edit::DuplicateLine),
+    ]
+}
+
+// A few small edits of `input`, the kind of damage a stray keystroke or a
+// cut off download does
+fn damaged(input: impl Strategy<Value = String>) -> impl Strategy<Value = String> {
+    (input, vec((any::<prop::sample::Index>(), edit()), 1..4)).prop_map(|(input, edits)| {
+        let mut chars: Vec<char> = input.chars().collect();
+        for (idx, edit) in edits {
+            if chars.is_empty() {
+                break;
+            }
+            let idx = idx.index(chars.len());
+
+            match edit {
+                Edit::Delete => {
+                    chars.remove(idx);
+                }
+                Edit::Insert(ch) => chars.insert(idx, ch),
+                Edit::Truncate => chars.truncate(idx),
+                Edit::DuplicateLine => {
+                    let start = chars[..idx]
+                        .iter()
+                        .rposition(|&ch| ch == '\n')
+                        .map_or(0, |newline| newline + 1);
+                    let end = chars[idx..]
+                        .iter()
+                        .position(|&ch| ch == '\n')
+                        .map_or(chars.len(), |newline| idx + newline + 1);
+                    let line = chars[start..end].to_vec();
+                    chars.splice(start..start, line);
+                }
+            }
+        }
+
+        chars.into_iter().collect()
+    })
+}
+
+macro_rules! parser_properties {
+    ($($name:ident: $day:expr => $input:expr),* $(,)?) => {
+        $(
+            mod $name {
+                use proptest::prelude::*;
+
+                use super::{damaged, parse};
+                use crate::days::inputs;
+
+                proptest! {
+                    #[test]
+                    fn generated_inputs_parse(input in $input.prop_map(|input| input.to_string())) {
+                        if let Err(err) = parse($day, &input) {
+                            prop_assert!(false, "{err}\n\n{input}");
+                        }
+                    }
+
+                    #[test]
+                    fn damaged_inputs_do_not_panic(
+                        input in damaged($input.prop_map(|input| input.to_string()))
+                    ) {
+                        let _ = parse($day, &input);
+                    }
+
+                    #[test]
+                    fn arbitrary_text_does_not_panic(input in any::<String>()) {
+                        let _ = parse($day, &input);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+parser_properties! {
+    day1: 1 => inputs::day1(),
+    day2: 2 => inputs::day2(),
+    day3: 3 => inputs::day3(),
+    day4: 4 => inputs::day4(),
+    day5: 5 => inputs::day5(),
+    day6: 6 => inputs::day6(),
+    day7: 7 => inputs::day7(),
+    day8: 8 => inputs::day8(),
+    day9: 9 => inputs::day9(),
+    day10: 10 => inputs::day10(),
+    day11: 11 => inputs::day11(),
+    day12: 12 => inputs::day12(),
+    day13: 13 => inputs::day13(),
+    day14: 14 => inputs::day14(),
+    day15: 15 => inputs::day15(),
+}
@@ -0,0 +1,33 @@
+//! Solutions for Advent of Code 2022.
+
+pub mod days;
+
+use aoc::Year;
+
+pub const YEAR: Year = Year {
+    year: 2022,
+    root: env!("CARGO_MANIFEST_DIR"),
+    days: days::DAYS,
+};
+
+#[cfg(test)]
+mod tests {
+    use aoc::{
+        answers::{self, Check},
+        store::InputStore,
+    };
+
+    use super::YEAR;
+
+    #[test]
+    fn recorded_answers_still_match() {
+        let recorded = answers::load_manifest(&YEAR).unwrap();
+        let store = InputStore::offline(InputStore::default_cache_dir());
+        let failures: Vec<Check> = answers::check(&store, &YEAR, &recorded, None)
+            .into_iter()
+            .filter(|check| !check.passed())
+            .collect();
+
+        assert!(failures.is_empty(), "answers changed: {failures:#?}");
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc::{
+    input::{self, InputSource},
+    params::Params,
+    solution::Part,
+    store::InputStore,
+    Day, Year,
+};
+
+// A stage slower than its baseline by more than this is reported as a
+// regression, smaller differences are usually just noise
+const REGRESSION_THRESHOLD: f64 = 0.25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    fn name(self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Solve(Part::One) => "part1",
+            Stage::Solve(Part::Two) => "part2",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "parse" => Some(Stage::Parse),
+            "part1" => Some(Stage::Solve(Part::One)),
+            "part2" => Some(Stage::Solve(Part::Two)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    pub day: u8,
+    pub stage: Stage,
+    pub median: Duration,
+    pub min: Duration,
+}
+
+pub fn default_baseline_path(year: &Year) -> PathBuf {
+    year.root().join("bench-baseline.txt")
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+/// Solves `day` of `year` on its real input `runs` times, with the puzzle
+/// parameters `params`, and returns the median and fastest time of parsing
+/// and of each part.
+pub fn bench_day(
+    store: &InputStore,
+    year: &Year,
+    day: &Day,
+    params: &Params,
+    runs: u32,
+) -> Result<Vec<Timing>, String> {
+    let input = input::load(store, year, day.day, &InputSource::Real)?;
+
+    let mut parse_durations = vec![];
+    let mut part_durations = [vec![], vec![]];
+    for _ in 0..runs {
+        let result = (day.solve)(&input, params, &Part::BOTH)
+            .map_err(|err| err.with_day(day.day).to_string())?;
+        parse_durations.push(result.parse_elapsed);
+        for (durations, part_result) in part_durations.iter_mut().zip(result.parts) {
+            durations.push(part_result.elapsed);
+        }
+    }
+
+    let stages = [
+        (Stage::Parse, parse_durations),
+        (Stage::Solve(Part::One), part_durations[0].clone()),
+        (Stage::Solve(Part::Two), part_durations[1].clone()),
+    ];
+
+    Ok(stages
+        .into_iter()
+        .map(|(stage, durations)| Timing {
+            day: day.day,
+            stage,
+            min: *durations.iter().min().expect("at least one run"),
+            median: median(durations),
+        })
+        .collect())
+}
+
+/// Reads a baseline written by `save_baseline`, every line has the form
+/// `<day> <parse|part1|part2> <median in nanoseconds>`.
+pub fn load_baseline(path: &Path) -> Result<Vec<(u8, Stage, Duration)>, String> {
+    let baseline = fs::read_to_string(path)
+        .map_err(|err| format!("could not read baseline {}: {err}", path.display()))?;
+
+    baseline
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            let invalid = || {
+                format!(
+                    "{} line {}: invalid entry {line:?}",
+                    path.display(),
+                    idx + 1
+                )
+            };
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [day, stage, nanos] = fields[..] else {
+                return Err(invalid());
+            };
+
+            let day = day.parse::<u8>().map_err(|_| invalid())?;
+            let stage = Stage::parse(stage).ok_or_else(invalid)?;
+            let nanos = nanos.parse::<u64>().map_err(|_| invalid())?;
+
+            Ok((day, stage, Duration::from_nanos(nanos)))
+        })
+        .collect()
+}
+
+/// Writes the medians of `timings` to `path`, entries of `previous` for
+/// stages which were not benchmarked this time are kept.
+pub fn save_baseline(
+    path: &Path,
+    timings: &[Timing],
+    previous: &[(u8, Stage, Duration)],
+) -> Result<(), String> {
+    let mut entries: Vec<(u8, Stage, Duration)> = previous
+        .iter()
+        .filter(|(day, stage, _)| {
+            !timings
+                .iter()
+                .any(|timing| timing.day == *day && timing.stage == *stage)
+        })
+        .copied()
+        .collect();
+    entries.extend(
+        timings
+            .iter()
+            .map(|timing| (timing.day, timing.stage, timing.median)),
+    );
+    entries.sort_by_key(|(day, stage, _)| (*day, stage.name()));
+
+    let baseline: String = entries
+        .iter()
+        .map(|(day, stage, median)| format!("{day} {} {}\n", stage.name(), median.as_nanos()))
+        .collect();
+
+    fs::write(path, baseline)
+        .map_err(|err| format!("could not write baseline {}: {err}", path.display()))
+}
+
+// The baseline median of the stage of `timing` and the relative change of
+// the median since, `None` without a usable baseline entry
+fn compare(timing: &Timing, baseline: &[(u8, Stage, Duration)]) -> Option<(Duration, f64)> {
+    let (_, _, baseline_median) = baseline
+        .iter()
+        .find(|(day, stage, _)| *day == timing.day && *stage == timing.stage)?;
+    if baseline_median.is_zero() {
+        return None;
+    }
+
+    let change = timing.median.as_secs_f64() / baseline_median.as_secs_f64() - 1.0;
+    Some((*baseline_median, change))
+}
+
+/// Prints one row per timing, comparing it with `baseline` when a matching
+/// entry exists. Returns how many stages regressed.
+pub fn print_table(timings: &[Timing], baseline: &[(u8, Stage, Duration)]) -> usize {
+    let mut regressions = 0;
+
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}  {:>8}",
+        "day", "stage", "median", "min", "baseline", "change"
+    );
+    for timing in timings {
+        let (baseline_column, change_column) = match compare(timing, baseline) {
+            Some((baseline_median, change)) => {
+                let mut change_column = format!("{:+.1}%", change * 100.0);
+                if change > REGRESSION_THRESHOLD {
+                    regressions += 1;
+                    change_column.push_str(" REGRESSED");
+                }
+
+                (format!("{baseline_median:.2?}"), change_column)
+            }
+            None => ("-".to_string(), "-".to_string()),
+        };
+
+        println!(
+            "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}  {:>8}",
+            timing.day,
+            timing.stage.name(),
+            format!("{:.2?}", timing.median),
+            format!("{:.2?}", timing.min),
+            baseline_column,
+            change_column
+        );
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    fn timing(day: u8, stage: Stage, median_micros: u64) -> Timing {
+        Timing {
+            day,
+            stage,
+            median: Duration::from_micros(median_micros),
+            min: Duration::from_micros(median_micros / 2),
+        }
+    }
+
+    #[test]
+    fn saved_baseline_loads_back() {
+        let path = env::temp_dir().join(format!("aoc-bench-baseline-{}.txt", process::id()));
+        let previous = [
+            (1, Stage::Parse, Duration::from_nanos(1500)),
+            (3, Stage::Solve(Part::Two), Duration::from_nanos(900)),
+        ];
+        let timings = [
+            timing(3, Stage::Solve(Part::Two), 40),
+            timing(2, Stage::Solve(Part::One), 7),
+        ];
+
+        save_baseline(&path, &timings, &previous).unwrap();
+        let loaded = load_baseline(&path);
+        let _ = fs::remove_file(&path);
+
+        // Re-benchmarked stages replace their entries, the others are kept
+        assert_eq!(
+            loaded.unwrap(),
+            [
+                (1, Stage::Parse, Duration::from_nanos(1500)),
+                (2, Stage::Solve(Part::One), Duration::from_micros(7)),
+                (3, Stage::Solve(Part::Two), Duration::from_micros(40)),
+            ]
+        );
+    }
+
+    #[test]
+    fn compares_with_the_matching_baseline_entry() {
+        let baseline = [
+            (1, Stage::Parse, Duration::from_micros(100)),
+            (1, Stage::Solve(Part::One), Duration::from_micros(100)),
+            (2, Stage::Parse, Duration::ZERO),
+        ];
+
+        let (median, change) =
+            compare(&timing(1, Stage::Solve(Part::One), 150), &baseline).unwrap();
+        assert_eq!(median, Duration::from_micros(100));
+        assert!((change - 0.5).abs() < 1e-9);
+
+        assert_eq!(
+            compare(&timing(1, Stage::Solve(Part::Two), 150), &baseline),
+            None
+        );
+        assert_eq!(compare(&timing(2, Stage::Parse, 150), &baseline), None);
+
+        // Only the stage slower by more than the threshold is a regression
+        let timings = [
+            timing(1, Stage::Parse, 120),
+            timing(1, Stage::Solve(Part::One), 150),
+            timing(2, Stage::Parse, 150),
+        ];
+        assert_eq!(print_table(&timings, &baseline), 1);
+    }
+}
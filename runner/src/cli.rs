@@ -0,0 +1,463 @@
+use std::{num::NonZeroUsize, path::PathBuf};
+
+use aoc::{input::InputSource, report::ReportFormat, visualize::ImageFormat};
+
+pub const USAGE: &str = "\
+usage: aoc run [--year <YEAR>] --day <N> [--part <P>] [--example | --input <PATH>]
+               [--param <NAME>=<VALUE>]... [--jobs <J>] [--format <FORMAT>]
+               [--visualize <SINK> [--every <K>]]
+       aoc run [--year <YEAR>] --all [--part <P>] [--example] [--jobs <J>]
+               [--format <FORMAT>]
+       aoc verify [--year <YEAR>] [--day <N>]
+       aoc bench [--year <YEAR>] [--day <N> [--param <NAME>=<VALUE>]...] [--runs <R>]
+                 [--baseline <PATH>] [--save-baseline]
+       aoc list [--year <YEAR>]
+       aoc submit [--year <YEAR>] --day <N> --part <P> [--param <NAME>=<VALUE>]...
+       aoc watch [--year <YEAR>] --day <N> [--example] [--param <NAME>=<VALUE>]...
+       aoc new --year <YEAR> --day <N>
+
+options:
+    --year <YEAR>   the year to use, the most recent one by default
+    --example       read days/dayN/example-input-dayN instead of the real input
+    --input <PATH>  read the input from PATH, `-` reads it from stdin
+    --param <NAME>=<VALUE>
+                    override a puzzle parameter, such as the row asked about.
+                    They are read from days/dayN/example-params-dayN for the
+                    example input and days/dayN/params-dayN for any other
+    --jobs <J>      run independent days, and independent work inside a day, on
+                    J worker threads, one per core by default. The output stays
+                    in day order
+    --format <FORMAT>
+                    print a report with one record per part instead of the
+                    answers, FORMAT is json, csv or junit. Records hold the
+                    answer, the answer recorded in answers.txt, whether they
+                    match and the parse and solve times
+    --visualize <SINK>
+                    send the frames of the simulation to SINK, `terminal`
+                    animates them, `ppm:<DIR>` and `png:<DIR>` write one image
+                    per frame and `gif:<FILE>` writes an animated GIF
+    --every <K>     only keep every K-th frame
+
+`aoc verify` checks the solvers against the answers recorded in the
+answers.txt of the year
+
+`aoc bench` times the parse stage and both parts on the real input, taking
+the median of R runs (10 by default). Timings are compared with the baseline
+file (bench-baseline.txt by default), --save-baseline overwrites it
+
+`aoc list` shows which parts of every day are solved
+
+`aoc submit` posts the answer to the real input, every submission is logged
+in the submissions.txt of the year. Answers known to be wrong are refused, as
+are submissions before the cooldown asked for by the site runs out
+
+`aoc watch` rebuilds and re-runs the day whenever its module, input or
+parameters change, next to the answers and timings of the previous run
+
+`aoc new` creates and registers the module, example input file and pending
+answers of a new day, it never overwrites existing files. No real input is
+created, the first `aoc run` of the day downloads it
+
+Real inputs are cached in inputs/<YEAR>/, missing ones are downloaded with the
+session token from $AOC_SESSION or the `session` of ~/.config/aoc/config";
+
+#[derive(Debug)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+    List(ListArgs),
+    Submit(SubmitArgs),
+    Watch(WatchArgs),
+    New(NewArgs),
+}
+
+#[derive(Debug)]
+pub struct RunArgs {
+    // `None` uses the most recent year
+    pub year: Option<u16>,
+    // `None` runs every registered day
+    pub day: Option<u8>,
+    // `None` runs both parts
+    pub part: Option<u8>,
+    pub input: InputSource,
+    // Puzzle parameters overriding the ones declared for the input
+    pub params: Vec<(String, String)>,
+    // `None` uses one worker per core
+    pub jobs: Option<NonZeroUsize>,
+    // `None` prints the answers as text
+    pub format: Option<ReportFormat>,
+    pub visualize: Option<VisualizeArgs>,
+}
+
+#[derive(Debug)]
+pub enum FrameSinkArg {
+    Terminal,
+    Images(ImageFormat, PathBuf),
+    Gif(PathBuf),
+}
+
+#[derive(Debug)]
+pub struct VisualizeArgs {
+    pub sink: FrameSinkArg,
+    pub every: usize,
+}
+
+#[derive(Debug)]
+pub struct VerifyArgs {
+    pub year: Option<u16>,
+    // `None` verifies every recorded day
+    pub day: Option<u8>,
+}
+
+#[derive(Debug)]
+pub struct BenchArgs {
+    pub year: Option<u16>,
+    // `None` benchmarks every registered day
+    pub day: Option<u8>,
+    pub runs: u32,
+    // Puzzle parameters overriding the ones of the real input
+    pub params: Vec<(String, String)>,
+    // `None` uses the default baseline file
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: bool,
+}
+
+#[derive(Debug)]
+pub struct ListArgs {
+    // `None` lists every year
+    pub year: Option<u16>,
+}
+
+#[derive(Debug)]
+pub struct SubmitArgs {
+    pub year: Option<u16>,
+    pub day: u8,
+    pub part: u8,
+    // Puzzle parameters overriding the ones of the real input
+    pub params: Vec<(String, String)>,
+}
+
+#[derive(Debug)]
+pub struct WatchArgs {
+    pub year: Option<u16>,
+    pub day: u8,
+    pub example: bool,
+    // Puzzle parameters overriding the ones declared for the input
+    pub params: Vec<(String, String)>,
+}
+
+#[derive(Debug)]
+pub struct NewArgs {
+    pub year: u16,
+    pub day: u8,
+}
+
+pub fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let command = args.next().ok_or("missing command")?;
+
+    match command.as_str() {
+        "run" => parse_run_args(args).map(Command::Run),
+        "verify" => parse_verify_args(args).map(Command::Verify),
+        "bench" => parse_bench_args(args).map(Command::Bench),
+        "list" => parse_list_args(args).map(Command::List),
+        "submit" => parse_submit_args(args).map(Command::Submit),
+        "watch" => parse_watch_args(args).map(Command::Watch),
+        "new" => parse_new_args(args).map(Command::New),
+        _ => Err(format!("unknown command: {command:?}")),
+    }
+}
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut part = None;
+    let mut all = false;
+    let mut input = InputSource::Real;
+    let mut params = vec![];
+    let mut jobs = None;
+    let mut format = None;
+    let mut sink = None;
+    let mut every = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, args.next())?),
+            "--day" => day = Some(parse_number(&arg, args.next(), 1..=25)?),
+            "--part" => part = Some(parse_number(&arg, args.next(), 1..=2)?),
+            "--all" => all = true,
+            "--jobs" => {
+                let value = args.next().ok_or("--jobs expects a value")?;
+                jobs = Some(
+                    value
+                        .parse::<NonZeroUsize>()
+                        .map_err(|_| format!("--jobs expects a positive number, got {value:?}"))?,
+                );
+            }
+            "--format" => {
+                let value = args.next().ok_or("--format expects a value")?;
+                format = Some(ReportFormat::parse(&value).ok_or_else(|| {
+                    format!("--format expects json, csv or junit, got {value:?}")
+                })?);
+            }
+            "--visualize" => sink = Some(parse_frame_sink(args.next())?),
+            "--every" => {
+                let value = args.next().ok_or("--every expects a value")?;
+                every = Some(
+                    value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|every| *every > 0)
+                        .ok_or_else(|| {
+                            format!("--every expects a positive number, got {value:?}")
+                        })?,
+                );
+            }
+            "--example" => input = set_input(input, InputSource::Example)?,
+            "--input" => {
+                let path = args.next().ok_or("--input expects a value")?;
+                let source = match path.as_str() {
+                    "-" => InputSource::Stdin,
+                    _ => InputSource::Path(PathBuf::from(path)),
+                };
+                input = set_input(input, source)?;
+            }
+            "--param" => params.push(parse_param(args.next())?),
+            _ => return Err(format!("unexpected argument: {arg:?}")),
+        }
+    }
+
+    if all && matches!(input, InputSource::Path(_) | InputSource::Stdin) {
+        return Err("--input can only be used together with --day".to_string());
+    }
+    // Parameters belong to one puzzle
+    if all && !params.is_empty() {
+        return Err("--param can only be used together with --day".to_string());
+    }
+
+    let visualize = match (sink, every) {
+        (Some(_), _) if all => {
+            return Err("--visualize can only be used together with --day".to_string())
+        }
+        (Some(sink), every) => Some(VisualizeArgs {
+            sink,
+            every: every.unwrap_or(1),
+        }),
+        (None, Some(_)) => return Err("--every needs --visualize".to_string()),
+        (None, None) => None,
+    };
+
+    match (day, all) {
+        (Some(_), true) => Err("--day and --all cannot be used together".to_string()),
+        (None, false) => Err("expected either --day or --all".to_string()),
+        _ => Ok(RunArgs {
+            year,
+            day,
+            part,
+            input,
+            params,
+            jobs,
+            format,
+            visualize,
+        }),
+    }
+}
+
+fn parse_frame_sink(value: Option<String>) -> Result<FrameSinkArg, String> {
+    let value = value.ok_or("--visualize expects a value")?;
+
+    match value.split_once(':') {
+        None if value == "terminal" => Ok(FrameSinkArg::Terminal),
+        Some(("ppm", dir)) if !dir.is_empty() => {
+            Ok(FrameSinkArg::Images(ImageFormat::Ppm, PathBuf::from(dir)))
+        }
+        Some(("png", dir)) if !dir.is_empty() => {
+            Ok(FrameSinkArg::Images(ImageFormat::Png, PathBuf::from(dir)))
+        }
+        Some(("gif", file)) if !file.is_empty() => Ok(FrameSinkArg::Gif(PathBuf::from(file))),
+        _ => Err(format!(
+            "--visualize expects terminal, ppm:<DIR>, png:<DIR> or gif:<FILE>, got {value:?}"
+        )),
+    }
+}
+
+fn parse_verify_args(mut args: impl Iterator<Item = String>) -> Result<VerifyArgs, String> {
+    let mut year = None;
+    let mut day = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, args.next())?),
+            "--day" => day = Some(parse_number(&arg, args.next(), 1..=25)?),
+            _ => return Err(format!("unexpected argument: {arg:?}")),
+        }
+    }
+
+    Ok(VerifyArgs { year, day })
+}
+
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        year: None,
+        day: None,
+        runs: 10,
+        params: vec![],
+        baseline: None,
+        save_baseline: false,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => bench_args.year = Some(parse_year(&arg, args.next())?),
+            "--day" => bench_args.day = Some(parse_number(&arg, args.next(), 1..=25)?),
+            "--runs" => {
+                let value = args.next().ok_or("--runs expects a value")?;
+                bench_args.runs = value
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|runs| *runs > 0)
+                    .ok_or_else(|| format!("--runs expects a positive number, got {value:?}"))?;
+            }
+            "--baseline" => {
+                let path = args.next().ok_or("--baseline expects a value")?;
+                bench_args.baseline = Some(PathBuf::from(path));
+            }
+            "--param" => bench_args.params.push(parse_param(args.next())?),
+            "--save-baseline" => bench_args.save_baseline = true,
+            _ => return Err(format!("unexpected argument: {arg:?}")),
+        }
+    }
+
+    // Parameters belong to one puzzle
+    if bench_args.day.is_none() && !bench_args.params.is_empty() {
+        return Err("--param can only be used together with --day".to_string());
+    }
+
+    Ok(bench_args)
+}
+
+fn parse_list_args(mut args: impl Iterator<Item = String>) -> Result<ListArgs, String> {
+    let mut year = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument: {arg:?}")),
+        }
+    }
+
+    Ok(ListArgs { year })
+}
+
+fn parse_submit_args(mut args: impl Iterator<Item = String>) -> Result<SubmitArgs, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut part = None;
+    let mut params = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, args.next())?),
+            "--day" => day = Some(parse_number(&arg, args.next(), 1..=25)?),
+            "--part" => part = Some(parse_number(&arg, args.next(), 1..=2)?),
+            "--param" => params.push(parse_param(args.next())?),
+            _ => return Err(format!("unexpected argument: {arg:?}")),
+        }
+    }
+
+    Ok(SubmitArgs {
+        year,
+        day: day.ok_or("missing --day")?,
+        part: part.ok_or("missing --part")?,
+        params,
+    })
+}
+
+fn parse_watch_args(mut args: impl Iterator<Item = String>) -> Result<WatchArgs, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut example = false;
+    let mut params = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, args.next())?),
+            "--day" => day = Some(parse_number(&arg, args.next(), 1..=25)?),
+            "--example" => example = true,
+            "--param" => params.push(parse_param(args.next())?),
+            _ => return Err(format!("unexpected argument: {arg:?}")),
+        }
+    }
+
+    Ok(WatchArgs {
+        year,
+        day: day.ok_or("missing --day")?,
+        example,
+        params,
+    })
+}
+
+fn parse_new_args(mut args: impl Iterator<Item = String>) -> Result<NewArgs, String> {
+    let mut year = None;
+    let mut day = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = Some(parse_year(&arg, args.next())?),
+            "--day" => day = Some(parse_number(&arg, args.next(), 1..=25)?),
+            _ => return Err(format!("unexpected argument: {arg:?}")),
+        }
+    }
+
+    Ok(NewArgs {
+        year: year.ok_or("missing --year")?,
+        day: day.ok_or("missing --day")?,
+    })
+}
+
+fn set_input(current: InputSource, new: InputSource) -> Result<InputSource, String> {
+    match current {
+        InputSource::Real => Ok(new),
+        _ => Err("--example and --input cannot be used together".to_string()),
+    }
+}
+
+fn parse_param(value: Option<String>) -> Result<(String, String), String> {
+    let value = value.ok_or("--param expects a value")?;
+    let (name, value) = value
+        .split_once('=')
+        .filter(|(name, _)| !name.trim().is_empty())
+        .ok_or_else(|| format!("--param expects <NAME>=<VALUE>, got {value:?}"))?;
+
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+fn parse_year(flag: &str, value: Option<String>) -> Result<u16, String> {
+    let value = value.ok_or_else(|| format!("{flag} expects a value"))?;
+
+    value
+        .parse::<u16>()
+        .ok()
+        .filter(|year| *year >= 2015)
+        .ok_or_else(|| format!("{flag} expects a year from 2015 on, got {value:?}"))
+}
+
+fn parse_number(
+    flag: &str,
+    value: Option<String>,
+    range: std::ops::RangeInclusive<u8>,
+) -> Result<u8, String> {
+    let value = value.ok_or_else(|| format!("{flag} expects a value"))?;
+
+    value
+        .parse::<u8>()
+        .ok()
+        .filter(|number| range.contains(number))
+        .ok_or_else(|| {
+            format!(
+                "{flag} expects a number between {} and {}, got {value:?}",
+                range.start(),
+                range.end()
+            )
+        })
+}
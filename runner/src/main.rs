@@ -0,0 +1,475 @@
+mod bench;
+mod cli;
+mod scaffold;
+mod watch;
+
+use std::{
+    env, process,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc::{
+    answers::{self, InputKind, RecordedAnswer},
+    config,
+    http::HttpClient,
+    input::{self, InputSource},
+    parallel,
+    params::{self, Params},
+    report::{self, Record},
+    solution::{Answer, DayResult, Part},
+    store::InputStore,
+    submit::{self, Verdict},
+    visualize::{self, FrameSink, GifSink, ImageSequenceSink, Sampled, TerminalSink},
+    Day, Year,
+};
+use cli::{
+    BenchArgs, Command, FrameSinkArg, ListArgs, NewArgs, RunArgs, SubmitArgs, VerifyArgs,
+    VisualizeArgs, WatchArgs,
+};
+
+// Every year with solutions in the workspace, oldest first
+const YEARS: &[Year] = &[aoc_2022::YEAR];
+
+// `None` picks the most recent year
+fn find_year(year: Option<u16>) -> Result<&'static Year, String> {
+    match year {
+        Some(year) => YEARS
+            .iter()
+            .find(|registered| registered.year == year)
+            .ok_or_else(|| format!("there are no solutions for {year} yet")),
+        None => Ok(YEARS.last().expect("at least one year is registered")),
+    }
+}
+
+// Downloads missing inputs only when there is a session token to do so
+fn input_store() -> Result<InputStore, String> {
+    let store = InputStore::offline(InputStore::default_cache_dir());
+
+    Ok(match config::session_token()? {
+        Some(session) => store.with_fetcher(HttpClient::new(session)),
+        None => store,
+    })
+}
+
+// `None` asks for both parts
+fn requested_parts(part: Option<u8>) -> Vec<Part> {
+    Part::BOTH
+        .into_iter()
+        .filter(|candidate| part.is_none_or(|part| part == candidate.number()))
+        .collect()
+}
+
+fn run_day(
+    store: &InputStore,
+    year: &Year,
+    day: &Day,
+    part: Option<u8>,
+    source: &InputSource,
+    overrides: &[(String, String)],
+) -> Result<DayResult, String> {
+    let input = input::load(store, year, day.day, source)?;
+    let params = load_params(year, day, source, overrides)?;
+
+    (day.solve)(&input, &params, &requested_parts(part))
+        .map_err(|err| err.with_day(day.day).to_string())
+}
+
+// The parameters declared for the input of a day, with the ones given on the
+// command line replacing them
+fn load_params(
+    year: &Year,
+    day: &Day,
+    source: &InputSource,
+    overrides: &[(String, String)],
+) -> Result<Params, String> {
+    let mut params = params::load(year, day.day, source)?;
+    for (name, value) in overrides {
+        params.set(name, value);
+    }
+
+    Ok(params)
+}
+
+// The answers and timings of a day, printed once the day is done so days
+// running concurrently do not interleave their output
+fn day_text(day: &Day, result: &DayResult) -> String {
+    let mut output = format!(
+        "== Day {} (parsed in {:?}) ==\n",
+        day.day, result.parse_elapsed
+    );
+    for part_result in &result.parts {
+        output += &format!(
+            "-- Part {} ({:?}) --\n{}\n",
+            part_result.part.number(),
+            part_result.elapsed,
+            part_result.answer
+        );
+    }
+
+    output
+}
+
+// One record per requested part, parts of a day which could not be run
+// all carry its error
+fn day_records(
+    year: &Year,
+    day: &Day,
+    parts: &[Part],
+    result: &Result<DayResult, String>,
+    recorded: &[RecordedAnswer],
+    kind: Option<InputKind>,
+) -> Vec<Record> {
+    parts
+        .iter()
+        .map(|&part| {
+            let expected = kind
+                .and_then(|kind| answers::expected(recorded, day.day, part, kind))
+                .map(str::to_string);
+            let (answer, parse_elapsed, solve_elapsed) = match result {
+                Ok(result) => {
+                    let part_result = result
+                        .parts
+                        .iter()
+                        .find(|part_result| part_result.part == part)
+                        .expect("every requested part is solved");
+                    (
+                        Ok(part_result.answer.to_string()),
+                        result.parse_elapsed,
+                        part_result.elapsed,
+                    )
+                }
+                Err(err) => (Err(err.clone()), Duration::ZERO, Duration::ZERO),
+            };
+
+            Record {
+                year: year.year,
+                day: day.day,
+                part,
+                answer,
+                expected,
+                parse_elapsed,
+                solve_elapsed,
+            }
+        })
+        .collect()
+}
+
+// Every cell of a frame is drawn as a square of this many pixels in images
+const FRAME_SCALE: usize = 4;
+const TERMINAL_FRAME_DELAY: Duration = Duration::from_millis(50);
+// In hundredths of a second
+const GIF_FRAME_DELAY: u16 = 5;
+
+fn frame_sink(args: VisualizeArgs) -> Box<dyn FrameSink> {
+    let sink: Box<dyn FrameSink> = match args.sink {
+        FrameSinkArg::Terminal => Box::new(TerminalSink {
+            delay: TERMINAL_FRAME_DELAY,
+        }),
+        FrameSinkArg::Images(format, dir) => {
+            Box::new(ImageSequenceSink::new(dir, format, FRAME_SCALE))
+        }
+        FrameSinkArg::Gif(path) => Box::new(GifSink::new(path, FRAME_SCALE, GIF_FRAME_DELAY)),
+    };
+
+    Box::new(Sampled::new(sink, args.every))
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let year = find_year(args.year)?;
+    let store = input_store()?;
+    if let Some(jobs) = args.jobs {
+        parallel::set_workers(jobs);
+    }
+
+    let days: Vec<&Day> = match args.day {
+        Some(day_number) => vec![year
+            .find(day_number)
+            .ok_or_else(|| format!("day {day_number} is not implemented yet"))?],
+        None => year.days.iter().collect(),
+    };
+
+    // Visualised runs are single days, see `cli::parse_run_args`
+    let results = match args.visualize {
+        Some(visualize) => vec![visualize::capture(frame_sink(visualize), || {
+            run_day(&store, year, days[0], args.part, &args.input, &args.params)
+        })?],
+        None => parallel::map(&days, |day| {
+            run_day(&store, year, day, args.part, &args.input, &args.params)
+        }),
+    };
+    // A day with a missing input should not hide the answers of the
+    // remaining days
+    let failed_days = results.iter().filter(|result| result.is_err()).count();
+
+    match args.format {
+        Some(format) => {
+            let recorded = answers::load_manifest(year)?;
+            let kind = InputKind::of_source(&args.input);
+            let parts = requested_parts(args.part);
+
+            let records: Vec<Record> = days
+                .iter()
+                .zip(&results)
+                .flat_map(|(day, result)| day_records(year, day, &parts, result, &recorded, kind))
+                .collect();
+            print!("{}", report::encode(format, &records));
+        }
+        None => {
+            for (day, result) in days.iter().zip(results) {
+                match result {
+                    Ok(result) => println!("{}", day_text(day, &result)),
+                    Err(err) if args.day.is_some() => return Err(err),
+                    Err(err) => eprintln!("error: day {}: {err}\n", day.day),
+                }
+            }
+        }
+    }
+
+    if failed_days > 0 {
+        return Err(format!("{failed_days} day(s) could not be run"));
+    }
+
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let year = find_year(args.year)?;
+    let recorded = answers::load_manifest(year)?;
+    let checks = answers::check(&input_store()?, year, &recorded, args.day);
+    let pending = recorded
+        .iter()
+        .filter(|answer| answer.is_pending() && args.day.is_none_or(|day| day == answer.day))
+        .count();
+    if checks.is_empty() && pending == 0 {
+        return Err("no recorded answers to verify".to_string());
+    }
+
+    let mut failed_checks = 0;
+    for check in &checks {
+        let recorded = &check.recorded;
+        let name = format!(
+            "day {} part {} ({})",
+            recorded.day,
+            recorded.part.number(),
+            recorded.kind.name()
+        );
+
+        match &check.actual {
+            _ if check.passed() => println!("ok      {name}"),
+            Ok(actual) => {
+                failed_checks += 1;
+                println!("FAILED  {name}");
+                println!("  expected: {}", recorded.answer);
+                println!("  got:      {actual}");
+            }
+            Err(err) => {
+                failed_checks += 1;
+                println!("FAILED  {name}: {err}");
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{} passed, {failed_checks} failed, {pending} pending",
+        checks.len() - failed_checks
+    );
+
+    if failed_checks > 0 {
+        return Err(format!("{failed_checks} answer(s) changed"));
+    }
+
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let year = find_year(args.year)?;
+    let days: Vec<&Day> = match args.day {
+        Some(day_number) => vec![year
+            .find(day_number)
+            .ok_or_else(|| format!("day {day_number} is not implemented yet"))?],
+        None => year.days.iter().collect(),
+    };
+
+    let store = input_store()?;
+    let mut timings = vec![];
+    for day in days {
+        let params = load_params(year, day, &InputSource::Real, &args.params)?;
+        timings.extend(bench::bench_day(&store, year, day, &params, args.runs)?);
+    }
+
+    let baseline_path = args
+        .baseline
+        .unwrap_or_else(|| bench::default_baseline_path(year));
+    let baseline = if baseline_path.exists() {
+        bench::load_baseline(&baseline_path)?
+    } else {
+        vec![]
+    };
+
+    let regressions = bench::print_table(&timings, &baseline);
+
+    if args.save_baseline {
+        bench::save_baseline(&baseline_path, &timings, &baseline)?;
+        println!("\nsaved baseline to {}", baseline_path.display());
+    } else if regressions > 0 {
+        println!("\n{regressions} stage(s) slower than the baseline");
+    }
+
+    Ok(())
+}
+
+// The parts of `day` which do not answer `Answer::Unsolved`, found by
+// running them on the example input, or on the real one if there is none
+fn solved_parts(store: &InputStore, year: &Year, day: &Day) -> Result<Vec<Part>, String> {
+    let (input, source) = match input::load(store, year, day.day, &InputSource::Example) {
+        Ok(input) => (input, InputSource::Example),
+        Err(_) => (
+            input::load(store, year, day.day, &InputSource::Real)?,
+            InputSource::Real,
+        ),
+    };
+    let params = params::load(year, day.day, &source)?;
+    let result = (day.solve)(&input, &params, &Part::BOTH)
+        .map_err(|err| err.with_day(day.day).to_string())?;
+
+    Ok(result
+        .parts
+        .into_iter()
+        .filter(|part_result| part_result.answer != Answer::Unsolved)
+        .map(|part_result| part_result.part)
+        .collect())
+}
+
+fn list(args: ListArgs) -> Result<(), String> {
+    let years = match args.year {
+        Some(_) => vec![find_year(args.year)?],
+        None => YEARS.iter().collect(),
+    };
+
+    let store = input_store()?;
+    for year in years {
+        for day in year.days {
+            let parts = match solved_parts(&store, year, day) {
+                Ok(parts) if parts.is_empty() => "no parts solved".to_string(),
+                Ok(parts) => parts
+                    .iter()
+                    .map(|part| format!("part {}", part.number()))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                Err(err) => format!("unknown, {err}"),
+            };
+            println!("{} day {:<2}  {parts}", year.year, day.day);
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<(), String> {
+    let year = find_year(args.year)?;
+    let day = year
+        .find(args.day)
+        .ok_or_else(|| format!("day {} is not implemented yet", args.day))?;
+    let part = Part::from_number(args.part).expect("the part is checked by the cli");
+
+    let input = input::load(&input_store()?, year, day.day, &InputSource::Real)?;
+    let params = load_params(year, day, &InputSource::Real, &args.params)?;
+    let result =
+        (day.solve)(&input, &params, &[part]).map_err(|err| err.with_day(day.day).to_string())?;
+    let answer = match &result.parts[0].answer {
+        Answer::Unsolved => return Err("this part is not implemented yet".to_string()),
+        // e.g. the letters drawn on the screen of 2022 day 10
+        answer if answer.to_string().contains('\n') => {
+            return Err(format!(
+                "the answer has to be read off the output, submit it by hand:\n{answer}"
+            ))
+        }
+        answer => answer.to_string(),
+    };
+
+    let session = config::session_token()?.ok_or_else(|| {
+        format!(
+            "submitting needs a session token, set ${} or `session` in the config file",
+            config::SESSION_ENV
+        )
+    })?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("the clock is past 1970")
+        .as_secs();
+
+    let history = submit::load_history(year)?;
+    println!("submitting {answer} for day {} part {}", day.day, args.part);
+    let submission = submit::submit(
+        &HttpClient::new(session),
+        year.year,
+        &history,
+        day.day,
+        part,
+        &answer,
+        now,
+    )?;
+    submit::append_history(year, &submission)?;
+
+    match submission.verdict {
+        Verdict::Right => println!(
+            "right answer, record it in answers.txt as `{} {} real {answer}`",
+            day.day, args.part
+        ),
+        Verdict::Throttled => println!("submitted too soon, wait {}s", submission.wait),
+        Verdict::AlreadySolved => println!("this part is already solved, or not unlocked yet"),
+        verdict => println!(
+            "wrong answer ({}), wait {}s before the next submission",
+            verdict.name(),
+            submission.wait
+        ),
+    }
+
+    Ok(())
+}
+
+fn watch(args: WatchArgs) -> Result<(), String> {
+    watch::watch(find_year(args.year)?, args.day, args.example, &args.params)
+}
+
+fn new(args: NewArgs) -> Result<(), String> {
+    let year = find_year(Some(args.year))?;
+
+    for path in scaffold::new_day(year.root(), args.day)? {
+        println!("wrote {}", path.display());
+    }
+    println!(
+        "the real input is downloaded to {} on the first run",
+        InputStore::offline(InputStore::default_cache_dir())
+            .cached_input_path(year.year, args.day)
+            .display()
+    );
+
+    Ok(())
+}
+
+fn main() {
+    let command = match cli::parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::List(args) => list(args),
+        Command::Submit(args) => submit(args),
+        Command::Watch(args) => watch(args),
+        Command::New(args) => new(args),
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,219 @@
+//! `aoc new`, creates the files of a new day from a template and registers
+//! it with the runner.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc::answers::PENDING_ANSWER;
+
+const DAY_TEMPLATE: &str = "\
+use aoc::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    // One entry per line of the puzzle input
+    type Input = Vec<String>;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Self::Answer1 {
+        Answer::Unsolved
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Answer2 {
+        Answer::Unsolved
+    }
+}
+";
+
+fn render_template(day: u8) -> String {
+    DAY_TEMPLATE.replace("{day}", &day.to_string())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("could not read {}: {err}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+// Adds `pub mod dayN;` to the sorted block of day modules and appends the
+// day to `DAYS`
+fn register_day(registry: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<String> = registry.lines().map(str::to_string).collect();
+
+    let mod_lines: Vec<usize> = (0..lines.len())
+        .filter(|&idx| lines[idx].starts_with("pub mod day"))
+        .collect();
+    let (Some(&first), Some(&last)) = (mod_lines.first(), mod_lines.last()) else {
+        return Err("expected `pub mod dayN;` declarations in the registry".to_string());
+    };
+
+    lines.insert(last + 1, format!("pub mod day{day};"));
+    // Sorted like rustfmt does, which puts day10 before day2
+    lines[first..=last + 1].sort_by(|a, b| a.trim_end_matches(';').cmp(b.trim_end_matches(';')));
+
+    let days_start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const DAYS"))
+        .ok_or("expected `pub const DAYS` in the registry")?;
+    let days_end = days_start
+        + lines[days_start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or("expected `DAYS` to end with `];`")?;
+
+    let entry = [
+        "    Day {".to_string(),
+        format!("        day: {day},"),
+        format!("        solve: solve::<day{day}::Day{day}>,"),
+        "    },".to_string(),
+    ];
+    lines.splice(days_end..days_end, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Adds the day to the `example_tests!` invocation
+fn register_example_test(examples: &str, day: u8) -> Result<String, String> {
+    let start = examples
+        .find("example_tests! {")
+        .ok_or("expected an `example_tests! {` invocation")?;
+    let end = start
+        + examples[start..]
+            .find("\n}")
+            .ok_or("expected `example_tests!` to end with `}`")?;
+
+    Ok(format!(
+        "{}\n    day{day}: {day},{}",
+        &examples[..end],
+        &examples[end..]
+    ))
+}
+
+fn add_pending_answers(manifest: &str, day: u8) -> String {
+    let mut manifest = manifest.trim_end().to_string() + "\n\n";
+    for kind in ["example", "real"] {
+        for part in [1, 2] {
+            manifest += &format!("{day} {part} {kind} {PENDING_ANSWER}\n");
+        }
+    }
+
+    manifest
+}
+
+/// Creates day `day` inside the crate at `root`, returning every file it
+/// created or changed. Nothing is written if any of the day's files
+/// already exist. The real input is left to the input store, which
+/// downloads it on the first run.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let module_path = root.join("src/days").join(format!("day{day}.rs"));
+    let input_dir = root.join("days").join(format!("day{day}"));
+    let example_path = input_dir.join(format!("example-input-day{day}"));
+    let registry_path = root.join("src/days/mod.rs");
+    let examples_path = root.join("src/days/examples.rs");
+    let manifest_path = root.join("answers.txt");
+
+    if let Some(existing) = [&module_path, &example_path]
+        .into_iter()
+        .find(|path| path.exists())
+    {
+        return Err(format!(
+            "{} already exists, refusing to overwrite it",
+            existing.display()
+        ));
+    }
+
+    let registry = read(&registry_path)?;
+    if registry.contains(&format!("pub mod day{day};")) {
+        return Err(format!("day {day} is already registered"));
+    }
+
+    let manifest = read(&manifest_path)?;
+    if manifest
+        .lines()
+        .any(|line| line.split(' ').next() == Some(&day.to_string()))
+    {
+        return Err(format!(
+            "{} already has answers for day {day}",
+            manifest_path.display()
+        ));
+    }
+
+    // Everything is prepared before the first write, so a failure cannot
+    // leave a half registered day behind
+    let registry = register_day(&registry, day)?;
+    let examples = register_example_test(&read(&examples_path)?, day)?;
+    let manifest = add_pending_answers(&manifest, day);
+
+    fs::create_dir_all(&input_dir)
+        .map_err(|err| format!("could not create {}: {err}", input_dir.display()))?;
+    write(&module_path, &render_template(day))?;
+    write(&example_path, "")?;
+    write(&registry_path, &registry)?;
+    write(&examples_path, &examples)?;
+    write(&manifest_path, &manifest)?;
+
+    Ok(vec![
+        module_path,
+        example_path,
+        registry_path,
+        examples_path,
+        manifest_path,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "\
+pub mod day1;
+pub mod day2;
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        solve: solve::<day1::Day1>,
+    },
+];
+";
+
+    #[test]
+    fn registers_module_and_day() {
+        let registry = register_day(REGISTRY, 10).unwrap();
+
+        assert!(registry.starts_with("pub mod day1;\npub mod day10;\npub mod day2;\n"));
+        assert!(registry.ends_with(
+            "    Day {\n        day: 10,\n        solve: solve::<day10::Day10>,\n    },\n];\n"
+        ));
+    }
+
+    #[test]
+    fn registers_example_test() {
+        let examples = "example_tests! {\n    day1: 1,\n}\n\n#[test]\nfn other() {}\n";
+
+        assert_eq!(
+            register_example_test(examples, 2).unwrap(),
+            "example_tests! {\n    day1: 1,\n    day2: 2,\n}\n\n#[test]\nfn other() {}\n"
+        );
+    }
+
+    #[test]
+    fn refuses_to_overwrite_existing_day() {
+        let err = new_day(aoc_2022::YEAR.root(), 1).unwrap_err();
+
+        assert!(err.contains("refusing to overwrite"), "{err}");
+    }
+}
@@ -0,0 +1,264 @@
+//! `aoc watch`, rebuilds and re-runs a day whenever its module or inputs
+//! change.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc::{
+    input, params,
+    report::{self, Record},
+    store::InputStore,
+    Year,
+};
+
+// How often the watched files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The stages of one `aoc run`, as read back from its JSON report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunSummary {
+    pub parse_elapsed: Duration,
+    // Number, time and answer of every part
+    pub parts: Vec<(u8, Duration, String)>,
+}
+
+/// Sums up the records of a run of one day, the error of the day if it
+/// could not be run.
+pub fn summarize(records: &[Record]) -> Result<RunSummary, String> {
+    let first = records.first().ok_or("the run reported no parts")?;
+
+    let mut parts = vec![];
+    for record in records {
+        parts.push((
+            record.part.number(),
+            record.solve_elapsed,
+            record.answer.clone()?,
+        ));
+    }
+
+    Ok(RunSummary {
+        parse_elapsed: first.parse_elapsed,
+        parts,
+    })
+}
+
+fn print_summary(summary: &RunSummary, previous: Option<&RunSummary>) {
+    println!("{:<8} {:<16} {:<16} previous", "stage", "time", "answer");
+
+    let previous_parse = previous.map_or(String::new(), |previous| {
+        format!("{:?}", previous.parse_elapsed)
+    });
+    println!(
+        "{:<8} {:<16} {:<16} {previous_parse}",
+        "parse",
+        format!("{:?}", summary.parse_elapsed),
+        ""
+    );
+
+    for (number, elapsed, answer) in &summary.parts {
+        let previous_part = previous.and_then(|previous| {
+            previous
+                .parts
+                .iter()
+                .find(|(previous_number, _, _)| previous_number == number)
+        });
+        let previous_column = match previous_part {
+            Some((_, elapsed, previous_answer)) if previous_answer != answer => {
+                format!("{elapsed:?}, answer was {}", first_line(previous_answer))
+            }
+            Some((_, elapsed, _)) => format!("{elapsed:?}"),
+            None => String::new(),
+        };
+
+        println!(
+            "{:<8} {:<16} {:<16} {previous_column}",
+            format!("part {number}"),
+            format!("{elapsed:?}"),
+            first_line(answer)
+        );
+    }
+
+    // Multi-line answers, like a screen of letters, do not fit in the table
+    for (number, _, answer) in &summary.parts {
+        if answer.contains('\n') {
+            println!("\n-- Part {number} --\n{answer}");
+        }
+    }
+}
+
+fn first_line(answer: &str) -> &str {
+    answer.lines().next().unwrap_or("")
+}
+
+fn modified_times(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| {
+            fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        })
+        .collect()
+}
+
+fn cargo(workspace: &Path, args: &[&str]) -> Result<Output, String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    Command::new(&cargo)
+        .args(args)
+        .current_dir(workspace)
+        .output()
+        .map_err(|err| format!("could not run {cargo}: {err}"))
+}
+
+// Rebuilds the runner and runs the day with it, compile and run errors are
+// printed in place of the answers
+fn build_and_run(workspace: &Path, run_args: &[String]) -> Result<Option<RunSummary>, String> {
+    let build = cargo(workspace, &["build", "--quiet", "--bin", "aoc"])?;
+    if !build.status.success() {
+        println!("compile error:\n");
+        println!("{}", String::from_utf8_lossy(&build.stderr).trim_end());
+        return Ok(None);
+    }
+
+    let mut args = vec![
+        "run", "--quiet", "--bin", "aoc", "--", "run", "--format", "json",
+    ];
+    args.extend(run_args.iter().map(String::as_str));
+    let run = cargo(workspace, &args)?;
+
+    // Days which fail still report their error, only runs which never get
+    // to the day, e.g. with rejected arguments, have nothing to read
+    let Some(records) = report::from_json(&String::from_utf8_lossy(&run.stdout)) else {
+        println!("{}", String::from_utf8_lossy(&run.stderr).trim_end());
+        return Ok(None);
+    };
+
+    match summarize(&records) {
+        Ok(summary) => Ok(Some(summary)),
+        Err(err) => {
+            println!("error: {err}");
+            Ok(None)
+        }
+    }
+}
+
+/// Re-runs `day` of `year` every time its module, example input, cached real
+/// input or parameter files change, until interrupted. `params` override the
+/// parameters declared for the input.
+pub fn watch(
+    year: &Year,
+    day: u8,
+    example: bool,
+    params: &[(String, String)],
+) -> Result<(), String> {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner crate is inside the workspace");
+    let paths = vec![
+        year.root().join("src/days").join(format!("day{day}.rs")),
+        input::example_input_path(year, day),
+        InputStore::offline(InputStore::default_cache_dir()).cached_input_path(year.year, day),
+        params::example_params_path(year, day),
+        params::params_path(year, day),
+    ];
+
+    let mut run_args = vec![
+        "--year".to_string(),
+        year.year.to_string(),
+        "--day".to_string(),
+        day.to_string(),
+    ];
+    if example {
+        run_args.push("--example".to_string());
+    }
+    for (name, value) in params {
+        run_args.push("--param".to_string());
+        run_args.push(format!("{name}={value}"));
+    }
+
+    let mut previous: Option<RunSummary> = None;
+    let mut last_modified = None;
+    let mut run = 0;
+    loop {
+        let modified = modified_times(&paths);
+        if last_modified.as_ref() == Some(&modified) {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+        last_modified = Some(modified);
+        run += 1;
+
+        // Clears the terminal, so only the latest run is on screen
+        print!("\x1b[2J\x1b[H");
+        println!(
+            "== {} day {day}{}, run {run} ==\n",
+            year.year,
+            if example { " (example)" } else { "" }
+        );
+
+        if let Some(summary) = build_and_run(workspace, &run_args)? {
+            print_summary(&summary, previous.as_ref());
+            previous = Some(summary);
+        }
+
+        println!("\nwatching:");
+        for path in &paths {
+            println!("    {}", path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::solution::Part;
+
+    use super::*;
+
+    fn record(part: Part, answer: Result<&str, &str>) -> Record {
+        Record {
+            year: 2022,
+            day: 10,
+            part,
+            answer: answer.map(str::to_string).map_err(str::to_string),
+            expected: None,
+            parse_elapsed: Duration::from_micros(20),
+            solve_elapsed: Duration::from_micros(3),
+        }
+    }
+
+    #[test]
+    fn summarizes_the_report_of_a_run() {
+        let records = [
+            record(Part::One, Ok("13140")),
+            record(Part::Two, Ok("##..\n..##")),
+        ];
+
+        assert_eq!(
+            summarize(&records),
+            Ok(RunSummary {
+                parse_elapsed: Duration::from_micros(20),
+                parts: vec![
+                    (1, Duration::from_micros(3), "13140".to_string()),
+                    (2, Duration::from_micros(3), "##..\n..##".to_string()),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn errors_of_the_day_are_reported() {
+        let records = [record(Part::One, Err("line 3: expected a number"))];
+
+        assert_eq!(
+            summarize(&records),
+            Err("line 3: expected a number".to_string())
+        );
+        assert!(summarize(&[]).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+use std::{fs, path::PathBuf};
+
+use crate::{
+    input::{self, InputSource},
+    params,
+    solution::Part,
+    store::InputStore,
+    Year,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Example,
+    Real,
+}
+
+impl InputKind {
+    fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "example" => Some(InputKind::Example),
+            "real" => Some(InputKind::Real),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            InputKind::Example => "example",
+            InputKind::Real => "real",
+        }
+    }
+
+    fn source(self) -> InputSource {
+        match self {
+            InputKind::Example => InputSource::Example,
+            InputKind::Real => InputSource::Real,
+        }
+    }
+
+    // `None` for inputs without recorded answers, read from a path or stdin
+    pub fn of_source(source: &InputSource) -> Option<Self> {
+        match source {
+            InputSource::Example => Some(InputKind::Example),
+            InputSource::Real => Some(InputKind::Real),
+            InputSource::Path(_) | InputSource::Stdin => None,
+        }
+    }
+}
+
+// Placeholder for answers which are not known yet, e.g. of a new day
+pub const PENDING_ANSWER: &str = "?";
+
+/// One line of the answer manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedAnswer {
+    pub day: u8,
+    pub part: Part,
+    pub kind: InputKind,
+    pub answer: String,
+}
+
+/// Parses the answer manifest, every non-empty line which is not a `#`
+/// comment has the form `<day> <part> <example|real> <answer>`.
+///
+/// The answer is the rest of the line, newlines in it are written as `\n`.
+pub fn parse_manifest(manifest: &str) -> Result<Vec<RecordedAnswer>, String> {
+    manifest
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| {
+            let invalid = |reason: &str| format!("answer manifest line {}: {reason}", idx + 1);
+
+            let mut fields = line.splitn(4, ' ');
+            let (Some(day), Some(part), Some(kind), Some(answer)) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid("expected `<day> <part> <example|real> <answer>`"));
+            };
+
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| invalid(&format!("invalid day {day:?}")))?;
+            let part = part
+                .parse()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(|| invalid(&format!("invalid part {part:?}")))?;
+            let kind =
+                InputKind::parse(kind).ok_or_else(|| invalid(&format!("invalid kind {kind:?}")))?;
+
+            Ok(RecordedAnswer {
+                day,
+                part,
+                kind,
+                answer: answer.replace("\\n", "\n"),
+            })
+        })
+        .collect()
+}
+
+impl RecordedAnswer {
+    pub fn is_pending(&self) -> bool {
+        self.answer == PENDING_ANSWER
+    }
+}
+
+/// The answer recorded for `part` of `day` on the input of `kind`, `None`
+/// if it is missing or pending.
+pub fn expected(recorded: &[RecordedAnswer], day: u8, part: Part, kind: InputKind) -> Option<&str> {
+    recorded
+        .iter()
+        .find(|answer| {
+            answer.day == day && answer.part == part && answer.kind == kind && !answer.is_pending()
+        })
+        .map(|answer| answer.answer.as_str())
+}
+
+pub fn manifest_path(year: &Year) -> PathBuf {
+    year.root().join("answers.txt")
+}
+
+pub fn load_manifest(year: &Year) -> Result<Vec<RecordedAnswer>, String> {
+    let path = manifest_path(year);
+    let manifest = fs::read_to_string(&path)
+        .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+
+    parse_manifest(&manifest)
+}
+
+/// The outcome of running a solver against one recorded answer.
+#[derive(Debug)]
+pub struct Check {
+    pub recorded: RecordedAnswer,
+    // The answer the solver gave now, or why it could not be run
+    pub actual: Result<String, String>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.recorded.answer)
+    }
+}
+
+/// Runs the solvers of `year` for every answer in `recorded` which is not
+/// pending, optionally only the ones of `day`. Each input is parsed once for
+/// all of its recorded parts.
+pub fn check(
+    store: &InputStore,
+    year: &Year,
+    recorded: &[RecordedAnswer],
+    day: Option<u8>,
+) -> Vec<Check> {
+    let mut groups: Vec<(u8, InputKind, Vec<&RecordedAnswer>)> = vec![];
+    for answer in recorded {
+        if day.is_some_and(|day| day != answer.day) || answer.is_pending() {
+            continue;
+        }
+
+        match groups
+            .iter_mut()
+            .find(|(day, kind, _)| *day == answer.day && *kind == answer.kind)
+        {
+            Some((_, _, answers)) => answers.push(answer),
+            None => groups.push((answer.day, answer.kind, vec![answer])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .flat_map(|(day, kind, answers)| {
+            let parts: Vec<Part> = answers.iter().map(|answer| answer.part).collect();
+
+            let results = year
+                .find(day)
+                .ok_or_else(|| format!("day {day} is not implemented yet"))
+                .and_then(|registered| {
+                    let input = input::load(store, year, day, &kind.source())?;
+                    let params = params::load(year, day, &kind.source())?;
+                    let result = (registered.solve)(&input, &params, &parts)
+                        .map_err(|err| err.with_day(day).to_string())?;
+                    Ok(result.parts)
+                });
+
+            answers
+                .into_iter()
+                .map(|answer| Check {
+                    recorded: answer.clone(),
+                    actual: match &results {
+                        Ok(results) => Ok(results
+                            .iter()
+                            .find(|result| result.part == answer.part)
+                            .expect("every requested part is solved")
+                            .answer
+                            .to_string()),
+                        Err(err) => Err(err.clone()),
+                    },
+                })
+                .collect::<Vec<Check>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_escapes_newlines() {
+        let recorded = parse_manifest("# comment\n\n10 2 example #.\\n.#\n").unwrap();
+
+        assert_eq!(
+            recorded,
+            vec![RecordedAnswer {
+                day: 10,
+                part: Part::Two,
+                kind: InputKind::Example,
+                answer: "#.\n.#".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn manifest_rejects_unknown_input_kind() {
+        let err = parse_manifest("1 1 puzzle 24000").unwrap_err();
+
+        assert_eq!(err, "answer manifest line 1: invalid kind \"puzzle\"");
+    }
+}
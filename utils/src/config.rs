@@ -0,0 +1,80 @@
+//! Settings read from the config file, `$XDG_CONFIG_HOME/aoc/config` or
+//! `~/.config/aoc/config`.
+
+use std::{env, path::PathBuf};
+
+use crate::key_value;
+
+// Takes precedence over the `session` of the config file
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    // Value of the `session` cookie of a logged in adventofcode.com user
+    pub session: Option<String>,
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    let config_dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+
+    Some(config_dir.join("aoc").join("config"))
+}
+
+/// Parses a config file, every non-empty line which is not a `#` comment
+/// has the form `<key> = <value>`.
+pub fn parse_config(config: &str) -> Result<Config, String> {
+    let mut parsed = Config::default();
+
+    for (line_number, key, value) in
+        key_value::parse(config).map_err(|err| format!("config {err}"))?
+    {
+        match key {
+            "session" => parsed.session = Some(value.to_string()),
+            key => return Err(format!("config line {line_number}: unknown key {key:?}")),
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Reads the config file, a missing file is the same as an empty one.
+pub fn load() -> Result<Config, String> {
+    let Some(path) = config_path() else {
+        return Ok(Config::default());
+    };
+
+    match key_value::read_optional(&path)? {
+        Some(config) => parse_config(&config).map_err(|err| format!("{}: {err}", path.display())),
+        None => Ok(Config::default()),
+    }
+}
+
+/// The session token from `AOC_SESSION`, or else from the config file.
+pub fn session_token() -> Result<Option<String>, String> {
+    match env::var(SESSION_ENV) {
+        Ok(session) if !session.trim().is_empty() => Ok(Some(session.trim().to_string())),
+        _ => Ok(load()?.session),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_session() {
+        let config = parse_config("# adventofcode.com\n\nsession = 53616c74\n").unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("53616c74"));
+    }
+
+    #[test]
+    fn rejects_unknown_key() {
+        let err = parse_config("token = 53616c74").unwrap_err();
+
+        assert_eq!(err, "config line 1: unknown key \"token\"");
+    }
+}
@@ -0,0 +1,83 @@
+use std::ops::{Add, Sub};
+
+/// A point on a 2D plane, `x` being the column and `y` the row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Coordinate<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Coordinate<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Coordinate { x, y }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Coordinate<T> {
+    /// Sum of the absolute differences of both axes.
+    pub fn manhattan_distance(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+}
+
+// Works for unsigned types too, where `(a - b).abs()` is not available
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T: Add<Output = T>> Add for Coordinate<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Coordinate {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Coordinate<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Coordinate {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_and_subtracts_per_axis() {
+        let a = Coordinate::new(3, -2);
+        let b = Coordinate::new(-1, 5);
+
+        assert_eq!(a + b, Coordinate::new(2, 3));
+        assert_eq!(a - b, Coordinate::new(4, -7));
+    }
+
+    #[test]
+    fn manhattan_distance_is_symmetric() {
+        let a = Coordinate::new(8i64, 7);
+        let b = Coordinate::new(2i64, 10);
+
+        assert_eq!(a.manhattan_distance(b), 9);
+        assert_eq!(b.manhattan_distance(a), 9);
+    }
+
+    #[test]
+    fn manhattan_distance_on_unsigned_coordinates() {
+        let a = Coordinate::new(1usize, 4);
+        let b = Coordinate::new(3usize, 0);
+
+        assert_eq!(a.manhattan_distance(b), 6);
+    }
+}
@@ -0,0 +1,251 @@
+//! Shapes made of diagonal lines, such as the positions within a manhattan
+//! distance of a point.
+//!
+//! Turning the plane by 45° makes their edges axis aligned: in the rotated
+//! coordinates `u = x + y` and `v = x - y`, a diamond is a square and all
+//! the geometry is integer ranges. `v` is negative below the diagonal, so
+//! these need a signed type.
+
+use std::ops::{Add, Div, RangeInclusive, Rem, Sub};
+
+use crate::Coordinate;
+
+/// A position in rotated coordinates, `u = x + y` and `v = x - y`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rotated<T> {
+    pub u: T,
+    pub v: T,
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T>> From<Coordinate<T>> for Rotated<T> {
+    fn from(coord: Coordinate<T>) -> Self {
+        Rotated {
+            u: coord.x + coord.y,
+            v: coord.x - coord.y,
+        }
+    }
+}
+
+impl<T> Rotated<T>
+where
+    T: Copy + Eq + Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T> + From<u8>,
+{
+    /// The position on the unrotated grid, `None` if `u` and `v` differ in
+    /// parity and so lie between positions.
+    pub fn to_coordinate(self) -> Option<Coordinate<T>> {
+        let two = T::from(2);
+        if (self.u + self.v) % two != T::from(0) {
+            return None;
+        }
+
+        Some(Coordinate::new(
+            (self.u + self.v) / two,
+            (self.u - self.v) / two,
+        ))
+    }
+}
+
+/// The positions whose rotated coordinates lie within a range of `u` and a
+/// range of `v`. Around a center these are the positions within a manhattan
+/// distance of it, overlapping two of them gives a rectangle turned by 45°.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Diamond<T> {
+    // Both inclusive
+    u_min: T,
+    u_max: T,
+    v_min: T,
+    v_max: T,
+}
+
+impl<T> Diamond<T>
+where
+    T: Copy
+        + Ord
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+        + From<u8>,
+{
+    /// The positions at most `radius` steps away from `center`.
+    pub fn around(center: Coordinate<T>, radius: T) -> Self {
+        let center = Rotated::from(center);
+
+        Diamond {
+            u_min: center.u - radius,
+            u_max: center.u + radius,
+            v_min: center.v - radius,
+            v_max: center.v + radius,
+        }
+    }
+
+    /// The `u` of the positions, the lines `x + y = u` bounding the top
+    /// left and bottom right edges.
+    pub fn u_range(&self) -> RangeInclusive<T> {
+        self.u_min..=self.u_max
+    }
+
+    /// The `v` of the positions, the lines `x - y = v` bounding the bottom
+    /// left and top right edges.
+    pub fn v_range(&self) -> RangeInclusive<T> {
+        self.v_min..=self.v_max
+    }
+
+    pub fn contains(&self, coord: Coordinate<T>) -> bool {
+        let rotated = Rotated::from(coord);
+        self.u_range().contains(&rotated.u) && self.v_range().contains(&rotated.v)
+    }
+
+    /// The columns of row `y` inside the diamond, `None` if it misses it.
+    pub fn row(&self, y: T) -> Option<RangeInclusive<T>> {
+        let start = (self.u_min - y).max(self.v_min + y);
+        let end = (self.u_max - y).min(self.v_max + y);
+
+        (start <= end).then_some(start..=end)
+    }
+
+    /// The positions on the edges of the diamond. Around a center these are
+    /// the positions exactly `radius` steps away.
+    pub fn boundary(&self) -> Vec<Coordinate<T>> {
+        let one = T::from(1);
+        let mut boundary = vec![];
+
+        let mut edge = |range: RangeInclusive<T>, fixed: &[T], along_u: bool| {
+            let (mut value, end) = range.into_inner();
+            while value <= end {
+                for &fixed in fixed {
+                    let rotated = if along_u {
+                        Rotated { u: value, v: fixed }
+                    } else {
+                        Rotated { u: fixed, v: value }
+                    };
+                    if let Some(coord) = rotated.to_coordinate() {
+                        boundary.push(coord);
+                    }
+                }
+                value = value + one;
+            }
+        };
+
+        // Ranges of a single line are only walked once
+        let fixed = |min: T, max: T| {
+            if min == max {
+                vec![min]
+            } else {
+                vec![min, max]
+            }
+        };
+
+        // The top left and bottom right edges, then the others between them
+        edge(self.v_range(), &fixed(self.u_min, self.u_max), false);
+        if self.u_min + one < self.u_max {
+            edge(
+                self.u_min + one..=self.u_max - one,
+                &fixed(self.v_min, self.v_max),
+                true,
+            );
+        }
+
+        boundary
+    }
+
+    /// The positions in both diamonds, `None` if they do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Diamond {
+            u_min: self.u_min.max(other.u_min),
+            u_max: self.u_max.min(other.u_max),
+            v_min: self.v_min.max(other.v_min),
+            v_max: self.v_max.min(other.v_max),
+        };
+
+        (intersection.u_min <= intersection.u_max && intersection.v_min <= intersection.v_max)
+            .then_some(intersection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn positions_near(radius: i64) -> impl Iterator<Item = Coordinate<i64>> {
+        let reach = radius + 3;
+        (-reach..=reach).flat_map(move |y| (-reach..=reach).map(move |x| Coordinate::new(x, y)))
+    }
+
+    #[test]
+    fn rotation_round_trips() {
+        let coord = Coordinate::new(-7i64, 4);
+        let rotated = Rotated::from(coord);
+
+        assert_eq!(rotated, Rotated { u: -3, v: -11 });
+        assert_eq!(rotated.to_coordinate(), Some(coord));
+        assert_eq!(Rotated { u: 0i64, v: 1 }.to_coordinate(), None);
+    }
+
+    #[test]
+    fn around_matches_manhattan_distance() {
+        let center = Coordinate::new(1i64, -2);
+        let diamond = Diamond::around(center, 3);
+
+        for coord in positions_near(5) {
+            let within = center.manhattan_distance(coord) <= 3;
+
+            assert_eq!(diamond.contains(coord), within, "{coord:?}");
+            assert_eq!(
+                diamond
+                    .row(coord.y)
+                    .is_some_and(|row| row.contains(&coord.x)),
+                within,
+                "{coord:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn boundary_is_the_positions_just_outside() {
+        let center = Coordinate::new(2i64, -1);
+
+        // The positions one step beyond `radius`, where a position just
+        // outside a diamond of that radius can be
+        for radius in 0..5 {
+            let boundary = Diamond::around(center, radius + 1).boundary();
+
+            let expected: HashSet<Coordinate<i64>> = positions_near(6)
+                .filter(|&coord| center.manhattan_distance(coord) == radius + 1)
+                .collect();
+            assert_eq!(boundary.len(), expected.len(), "radius {radius}");
+            assert_eq!(boundary.into_iter().collect::<HashSet<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn intersection_holds_positions_in_both() {
+        let a = Diamond::around(Coordinate::new(0i64, 0), 3);
+        let overlapping = Diamond::around(Coordinate::new(4i64, 1), 2);
+        let touching = Diamond::around(Coordinate::new(5i64, 0), 2);
+        let disjoint = Diamond::around(Coordinate::new(6i64, 0), 2);
+
+        for (other, both) in [
+            (overlapping, a.intersection(&overlapping).unwrap()),
+            (touching, a.intersection(&touching).unwrap()),
+        ] {
+            for coord in positions_near(8) {
+                assert_eq!(
+                    both.contains(coord),
+                    a.contains(coord) && other.contains(coord),
+                    "{coord:?}"
+                );
+            }
+        }
+
+        // Touching diamonds only share the position where their tips meet
+        let tip = a.intersection(&touching).unwrap();
+        assert_eq!(tip.boundary(), [Coordinate::new(3, 0)]);
+        assert_eq!(tip.row(0), Some(3..=3));
+
+        assert_eq!(a.intersection(&disjoint), None);
+        assert!(positions_near(8).all(|coord| !(a.contains(coord) && disjoint.contains(coord))));
+    }
+}
@@ -0,0 +1,287 @@
+//! A dense, bounded 2D grid stored row after row in a single `Vec`.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::Coordinate;
+
+pub type Position = Coordinate<usize>;
+
+/// One step in one of the four axis directions, `Up` decreasing the row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn step(self) -> Coordinate<isize> {
+        match self {
+            Direction::Up => Coordinate::new(0, -1),
+            Direction::Down => Coordinate::new(0, 1),
+            Direction::Left => Coordinate::new(-1, 0),
+            Direction::Right => Coordinate::new(1, 0),
+        }
+    }
+}
+
+const NEIGHBOUR_STEPS_8: [Coordinate<isize>; 8] = [
+    Coordinate::new(-1, -1),
+    Coordinate::new(0, -1),
+    Coordinate::new(1, -1),
+    Coordinate::new(-1, 0),
+    Coordinate::new(1, 0),
+    Coordinate::new(-1, 1),
+    Coordinate::new(0, 1),
+    Coordinate::new(1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Builds a grid out of equally long rows, `None` if they are not.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+
+        Some(Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one row per non-empty line, mapping every character with
+    /// `parse_cell`. `None` if the lines are not equally long.
+    pub fn parse(text: &str, parse_cell: impl Fn(char) -> T) -> Option<Self> {
+        let rows = text
+            .lines()
+            .map(str::trim_end)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().map(&parse_cell).collect())
+            .collect();
+
+        Self::from_rows(rows)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.x < self.cols && pos.y < self.rows
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.cols + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.y * self.cols + pos.x])
+    }
+
+    /// The position one `step` away from `pos`, `None` once it leaves the
+    /// grid.
+    pub fn offset(&self, pos: Position, step: Coordinate<isize>) -> Option<Position> {
+        let next = Coordinate::new(
+            pos.x.checked_add_signed(step.x)?,
+            pos.y.checked_add_signed(step.y)?,
+        );
+
+        self.contains(next).then_some(next)
+    }
+
+    /// The up to 4 positions sharing an edge with `pos`.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(pos, direction.step()))
+    }
+
+    /// The up to 8 positions sharing an edge or a corner with `pos`.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOUR_STEPS_8
+            .into_iter()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    /// Every position reached by repeatedly moving `step` away from `pos`,
+    /// excluding `pos` itself, until the edge of the grid.
+    pub fn ray(
+        &self,
+        pos: Position,
+        step: Coordinate<isize>,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let mut curr = Some(pos);
+        std::iter::from_fn(move || {
+            curr = self.offset(curr?, step);
+            curr
+        })
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.cells[y * self.cols..(y + 1) * self.cols].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.cols.max(1))
+    }
+
+    /// Every position, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |y| (0..cols).map(move |x| Coordinate::new(x, y)))
+    }
+
+    /// Every cell with its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of a {}x{} grid", self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of a {rows}x{cols} grid"))
+    }
+}
+
+/// Renders every row on its own line, without separators between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.rows {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(y) {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |ch| ch).unwrap()
+    }
+
+    #[test]
+    fn parses_rows_and_columns() {
+        let grid = sample();
+
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[Coordinate::new(2, 1)], 'f');
+        assert_eq!(grid.row(1).collect::<String>(), "def");
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert_eq!(Grid::parse("ab\nc", |ch| ch), None);
+    }
+
+    #[test]
+    fn get_is_bounds_checked() {
+        let mut grid = sample();
+
+        assert_eq!(grid.get(Coordinate::new(3, 0)), None);
+        assert_eq!(grid.get(Coordinate::new(0, 2)), None);
+
+        *grid.get_mut(Coordinate::new(0, 0)).unwrap() = 'z';
+        assert_eq!(grid.to_string(), "zbc\ndef");
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = sample();
+
+        let corner: Vec<Position> = grid.neighbours4(Coordinate::new(0, 0)).collect();
+        assert_eq!(corner, vec![Coordinate::new(0, 1), Coordinate::new(1, 0)]);
+
+        assert_eq!(grid.neighbours8(Coordinate::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8(Coordinate::new(0, 1)).count(), 3);
+    }
+
+    #[test]
+    fn ray_walks_until_the_edge() {
+        let grid = sample();
+
+        let cells: String = grid
+            .ray(Coordinate::new(0, 0), Direction::Right.step())
+            .map(|pos| grid[pos])
+            .collect();
+        assert_eq!(cells, "bc");
+
+        assert_eq!(
+            grid.ray(Coordinate::new(0, 0), Direction::Up.step())
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn displays_one_row_per_line() {
+        assert_eq!(
+            sample().map(|ch| ch.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF"
+        );
+    }
+}
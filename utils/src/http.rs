@@ -0,0 +1,181 @@
+//! The adventofcode.com client, requests are sent with `curl`.
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use crate::{solution::Part, store::Fetch, submit::Submit};
+
+pub const ADVENT_OF_CODE_URL: &str = "https://adventofcode.com";
+
+// Lets the site owner know who is sending the requests
+const USER_AGENT: &str = "github.com/feniljain/advent-of-code";
+
+/// Talks to adventofcode.com as the user whose session cookie it holds.
+pub struct HttpClient {
+    base_url: String,
+    session: String,
+}
+
+impl HttpClient {
+    pub fn new(session: String) -> Self {
+        Self::with_base_url(ADVENT_OF_CODE_URL, session)
+    }
+
+    // `base_url` replaces `https://adventofcode.com`, e.g. to talk to a
+    // local server in tests
+    pub fn with_base_url(base_url: &str, session: String) -> Self {
+        HttpClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    // Sends a GET, or a POST of `form` when there are any fields
+    fn request(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let url = format!("{}{path}", self.base_url);
+
+        let mut curl = Command::new("curl");
+        curl.args(["--silent", "--show-error", "--fail", "--location"])
+            .args(["--user-agent", USER_AGENT, "--header", "@-"]);
+        for (name, value) in form {
+            curl.args(["--data-urlencode", &format!("{name}={value}")]);
+        }
+
+        // The cookie is passed on stdin, so the token does not show up in
+        // the process list
+        let mut curl = curl
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("could not run curl to request {url}: {err}"))?;
+
+        curl.stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(format!("Cookie: session={}\n", self.session).as_bytes())
+            .map_err(|err| format!("could not pass the session to curl: {err}"))?;
+
+        let output = curl
+            .wait_with_output()
+            .map_err(|err| format!("could not request {url}: {err}"))?;
+        if !output.status.success() {
+            return Err(format!(
+                "could not request {url}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        String::from_utf8(output.stdout).map_err(|_| format!("{url} did not return text"))
+    }
+}
+
+impl Fetch for HttpClient {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        self.request(&format!("/{year}/day/{day}/input"), &[])
+    }
+}
+
+impl Submit for HttpClient {
+    fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String, String> {
+        self.request(
+            &format!("/{year}/day/{day}/answer"),
+            &[("level", &part.number().to_string()), ("answer", answer)],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+
+    // A stand-in server answering a single request with `body`, the handle
+    // returns the request line, the cookie and the request body
+    fn serve_once(body: &'static str) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+
+            let mut request = vec![];
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+
+                if let Some(length) = line.strip_prefix("Content-Length: ") {
+                    content_length = length.parse().unwrap();
+                }
+                if line.starts_with("GET") || line.starts_with("POST") || line.starts_with("Cookie")
+                {
+                    request.push(line.to_string());
+                }
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push(String::from_utf8(request_body).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, server)
+    }
+
+    #[test]
+    fn fetch_sends_session_cookie() {
+        let (base_url, server) = serve_once("1000\n2000\n");
+        let client = HttpClient::with_base_url(&base_url, "53616c74".to_string());
+
+        assert_eq!(client.fetch(2022, 3).unwrap(), "1000\n2000\n");
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                "GET /2022/day/3/input HTTP/1.1",
+                "Cookie: session=53616c74",
+                ""
+            ]
+        );
+    }
+
+    #[test]
+    fn submit_posts_level_and_answer() {
+        let (base_url, server) = serve_once("<article><p>That's the right answer!</p></article>");
+        let client = HttpClient::with_base_url(&base_url, "53616c74".to_string());
+
+        assert_eq!(
+            client.submit(2022, 3, Part::Two, "a b").unwrap(),
+            "<article><p>That's the right answer!</p></article>"
+        );
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                "POST /2022/day/3/answer HTTP/1.1",
+                "Cookie: session=53616c74",
+                "level=2&answer=a+b"
+            ]
+        );
+    }
+}
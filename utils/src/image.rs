@@ -0,0 +1,478 @@
+//! Minimal PPM, PNG and GIF encoders for frames of RGB pixels.
+
+use std::{
+    collections::HashMap,
+    io::{self, Cursor, Seek, SeekFrom, Write},
+};
+
+pub type Rgb = [u8; 3];
+
+/// A picture of `width * height` pixels, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn row(&self, y: usize) -> &[Rgb] {
+        &self.pixels[y * self.width..(y + 1) * self.width]
+    }
+}
+
+pub fn encode_ppm(image: &Image) -> Vec<u8> {
+    let mut ppm = format!("P6\n{} {}\n255\n", image.width, image.height).into_bytes();
+    ppm.extend(image.pixels.iter().flatten());
+    ppm
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// Encodes an RGB PNG. The pixels are stored without compression, which
+/// keeps the encoder tiny and is good enough for debugging output.
+pub fn encode_png(image: &Image) -> Vec<u8> {
+    // Every scanline starts with its filter type, 0 for none
+    let mut scanlines = vec![];
+    for y in 0..image.height {
+        scanlines.push(0);
+        scanlines.extend(image.row(y).iter().flatten());
+    }
+
+    // A zlib stream of stored deflate blocks
+    let mut zlib = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = scanlines.chunks(usize::from(u16::MAX)).collect();
+    for (idx, block) in blocks.iter().enumerate() {
+        let len = block.len() as u16;
+        zlib.push(u8::from(idx + 1 == blocks.len()));
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(*block);
+    }
+    if blocks.is_empty() {
+        zlib.extend([1, 0, 0, 0xFF, 0xFF]);
+    }
+    zlib.extend(adler32(&scanlines).to_be_bytes());
+
+    let mut header = vec![];
+    header.extend((image.width as u32).to_be_bytes());
+    header.extend((image.height as u32).to_be_bytes());
+    // 8 bits per channel, RGB, default compression, filtering and no
+    // interlacing
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+    png_chunk(&mut png, b"IHDR", &header);
+    png_chunk(&mut png, b"IDAT", &zlib);
+    png_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+// Packs variable width codes least significant bit first
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u32) {
+        self.buffer |= u32::from(code) << self.bits;
+        self.bits += width;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+// The largest code GIF allows is 12 bits wide
+const MAX_LZW_CODE: u16 = 4095;
+
+fn lzw_encode(indices: &[u8], min_code_size: u32) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut writer = BitWriter::default();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next_code = end + 1;
+    let mut code_size = min_code_size + 1;
+
+    writer.write(clear, code_size);
+
+    let mut current: Option<u16> = None;
+    for &index in indices {
+        let Some(prefix) = current else {
+            current = Some(u16::from(index));
+            continue;
+        };
+
+        if let Some(&code) = table.get(&(prefix, index)) {
+            current = Some(code);
+            continue;
+        }
+
+        writer.write(prefix, code_size);
+        if next_code <= MAX_LZW_CODE {
+            table.insert((prefix, index), next_code);
+            next_code += 1;
+            if next_code > (1 << code_size) && code_size < 12 {
+                code_size += 1;
+            }
+        } else {
+            writer.write(clear, code_size);
+            table.clear();
+            next_code = end + 1;
+            code_size = min_code_size + 1;
+        }
+        current = Some(u16::from(index));
+    }
+
+    if let Some(prefix) = current {
+        writer.write(prefix, code_size);
+    }
+    writer.write(end, code_size);
+
+    writer.finish()
+}
+
+// The global colour table always has 256 entries, so the header can be
+// written before the colours are known and `finish` fills them in
+const GIF_PALETTE_LEN: usize = 256;
+const GIF_SIZE_OFFSET: u64 = 6;
+const GIF_PALETTE_OFFSET: u64 = 13;
+
+fn gif_dimension(pixels: usize) -> Result<[u8; 2], String> {
+    u16::try_from(pixels).map(u16::to_le_bytes).map_err(|_| {
+        format!(
+            "GIFs are at most {} pixels wide and high, got {pixels}",
+            u16::MAX
+        )
+    })
+}
+
+fn io_error(err: io::Error) -> String {
+    err.to_string()
+}
+
+/// Writes a looping GIF frame by frame, so only one frame is in memory at a
+/// time, showing every frame for `delay` hundredths of a second. Frames
+/// smaller than the largest one so far are drawn in its top left corner
+/// over `background`.
+pub struct GifEncoder<W> {
+    out: W,
+    delay: u16,
+    // Of the largest frame so far
+    width: usize,
+    height: usize,
+    palette: Vec<Rgb>,
+    palette_index: HashMap<Rgb, u8>,
+}
+
+impl<W: Write + Seek> GifEncoder<W> {
+    pub fn new(mut out: W, delay: u16, background: Rgb) -> Result<Self, String> {
+        let depth = GIF_PALETTE_LEN.trailing_zeros() as u8;
+
+        let mut header = b"GIF89a".to_vec();
+        // The size, written by `finish`
+        header.extend([0; 4]);
+        header.extend([0x80 | ((depth - 1) << 4) | (depth - 1), 0, 0]);
+        header.extend([0; 3 * GIF_PALETTE_LEN]);
+
+        // Loops forever
+        header.extend([0x21, 0xFF, 0x0B]);
+        header.extend(b"NETSCAPE2.0");
+        header.extend([0x03, 0x01, 0x00, 0x00, 0x00]);
+
+        out.write_all(&header).map_err(io_error)?;
+
+        Ok(GifEncoder {
+            out,
+            delay,
+            width: 0,
+            height: 0,
+            palette: vec![background],
+            palette_index: HashMap::from([(background, 0)]),
+        })
+    }
+
+    /// Appends `frame`. Fails if the frames grow wider or higher than a GIF
+    /// allows or use more than 256 colours.
+    pub fn push(&mut self, frame: &Image) -> Result<(), String> {
+        let width = self.width.max(frame.width);
+        let height = self.height.max(frame.height);
+        let (width_bytes, height_bytes) = (gif_dimension(width)?, gif_dimension(height)?);
+        (self.width, self.height) = (width, height);
+
+        let mut indices = vec![0u8; width * height];
+        for y in 0..frame.height {
+            for (x, &colour) in frame.row(y).iter().enumerate() {
+                let index = match self.palette_index.get(&colour) {
+                    Some(&index) => index,
+                    None => {
+                        let index = u8::try_from(self.palette.len())
+                            .map_err(|_| "the frames use more than 256 colours")?;
+                        self.palette.push(colour);
+                        self.palette_index.insert(colour, index);
+                        index
+                    }
+                };
+                indices[y * width + x] = index;
+            }
+        }
+
+        let mut gif = vec![0x21, 0xF9, 0x04, 0x00];
+        gif.extend(self.delay.to_le_bytes());
+        gif.extend([0x00, 0x00]);
+
+        gif.push(0x2C);
+        gif.extend([0, 0, 0, 0]);
+        gif.extend(width_bytes);
+        gif.extend(height_bytes);
+        gif.push(0);
+
+        let min_code_size = GIF_PALETTE_LEN.trailing_zeros();
+        gif.push(min_code_size as u8);
+        for block in lzw_encode(&indices, min_code_size).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0);
+
+        self.out.write_all(&gif).map_err(io_error)
+    }
+
+    /// Ends the GIF and fills in its size and colours.
+    pub fn finish(mut self) -> Result<W, String> {
+        let mut palette = self.palette.clone();
+        palette.resize(GIF_PALETTE_LEN, [0, 0, 0]);
+
+        let size = [gif_dimension(self.width)?, gif_dimension(self.height)?].concat();
+        self.out.write_all(&[0x3B]).map_err(io_error)?;
+        for (offset, bytes) in [
+            (GIF_SIZE_OFFSET, size),
+            (GIF_PALETTE_OFFSET, palette.concat()),
+        ] {
+            self.out
+                .seek(SeekFrom::Start(offset))
+                .and_then(|_| self.out.write_all(&bytes))
+                .map_err(io_error)?;
+        }
+        self.out
+            .seek(SeekFrom::End(0))
+            .and_then(|_| self.out.flush())
+            .map_err(io_error)?;
+
+        Ok(self.out)
+    }
+}
+
+/// Encodes the frames as a looping GIF in memory, see `GifEncoder`.
+pub fn encode_gif(frames: &[Image], delay: u16, background: Rgb) -> Result<Vec<u8>, String> {
+    let mut encoder = GifEncoder::new(Cursor::new(vec![]), delay, background)?;
+    for frame in frames {
+        encoder.push(frame)?;
+    }
+
+    Ok(encoder.finish()?.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lzw_decode(bytes: &[u8], min_code_size: u32) -> Vec<u8> {
+        let clear = 1u16 << min_code_size;
+        let end = clear + 1;
+
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut code_size = min_code_size + 1;
+        let mut previous: Option<Vec<u8>> = None;
+        let mut output = vec![];
+
+        let (mut buffer, mut bits, mut pos) = (0u32, 0u32, 0);
+        loop {
+            while bits < code_size {
+                buffer |= u32::from(bytes[pos]) << bits;
+                pos += 1;
+                bits += 8;
+            }
+            let code = (buffer & ((1 << code_size) - 1)) as u16;
+            buffer >>= code_size;
+            bits -= code_size;
+
+            if code == clear {
+                table = (0..clear).map(|index| vec![index as u8]).collect();
+                table.push(vec![]);
+                table.push(vec![]);
+                code_size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return output;
+            }
+
+            let entry = match (table.get(usize::from(code)), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.clone(), vec![previous[0]]].concat(),
+                (None, None) => panic!("unknown first code"),
+            };
+            if let Some(previous) = previous {
+                if table.len() <= usize::from(MAX_LZW_CODE) {
+                    table.push([previous, vec![entry[0]]].concat());
+                }
+            }
+            if table.len() == 1 << code_size && code_size < 12 {
+                code_size += 1;
+            }
+
+            output.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn lzw_round_trips() {
+        // Long enough to fill the code table and force a clear code
+        let indices: Vec<u8> = (0..40_000u32)
+            .map(|i| ((i * 7919) % 13 + i / 3000 % 3) as u8)
+            .collect();
+
+        let encoded = lzw_encode(&indices, 4);
+
+        assert_eq!(lzw_decode(&encoded, 4), indices);
+    }
+
+    #[test]
+    fn png_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn ppm_has_header_and_pixels() {
+        let image = Image {
+            width: 2,
+            height: 1,
+            pixels: vec![[255, 0, 0], [0, 0, 255]],
+        };
+
+        assert_eq!(
+            encode_ppm(&image),
+            b"P6\n2 1\n255\n\xFF\x00\x00\x00\x00\xFF".to_vec()
+        );
+    }
+
+    // The decoded colour indices of every frame of a GIF written by
+    // `GifEncoder`
+    fn gif_frames(gif: &[u8]) -> Vec<Vec<u8>> {
+        let mut frames = vec![];
+        // After the header, the colour table and the looping extension
+        let mut pos = 13 + 3 * GIF_PALETTE_LEN + 19;
+        while gif[pos] != 0x3B {
+            // The graphic control extension and the image descriptor
+            pos += 8;
+            assert_eq!(gif[pos], 0x2C);
+            pos += 10;
+
+            let min_code_size = u32::from(gif[pos]);
+            pos += 1;
+            let mut data = vec![];
+            while gif[pos] != 0 {
+                let len = usize::from(gif[pos]);
+                data.extend(&gif[pos + 1..pos + 1 + len]);
+                pos += 1 + len;
+            }
+            pos += 1;
+
+            frames.push(lzw_decode(&data, min_code_size));
+        }
+
+        frames
+    }
+
+    #[test]
+    fn gif_pads_smaller_frames_and_fills_in_the_header() {
+        let red = [255, 0, 0];
+        let background = [0, 0, 32];
+        let wide = Image {
+            width: 3,
+            height: 1,
+            pixels: vec![red; 3],
+        };
+        let small = Image {
+            width: 1,
+            height: 2,
+            pixels: vec![red, [0, 255, 0]],
+        };
+
+        let gif = encode_gif(&[wide, small], 5, background).unwrap();
+
+        assert_eq!(&gif[..6], b"GIF89a");
+        // 3 by 2 pixels, the largest width and height of the frames
+        assert_eq!(&gif[6..10], [3, 0, 2, 0]);
+        assert_eq!(&gif[13..22], [0, 0, 32, 255, 0, 0, 0, 255, 0]);
+        assert_eq!(gif.last(), Some(&0x3B));
+
+        // The second frame is padded to the width of the first one
+        assert_eq!(gif_frames(&gif), [vec![1, 1, 1], vec![1, 0, 0, 2, 0, 0]]);
+    }
+
+    #[test]
+    fn gif_rejects_frames_larger_than_the_format_allows() {
+        let tall = Image {
+            width: 1,
+            height: 70_000,
+            pixels: vec![[0, 0, 0]; 70_000],
+        };
+
+        assert_eq!(
+            encode_gif(&[tall], 5, [0, 0, 0]),
+            Err("GIFs are at most 65535 pixels wide and high, got 70000".to_string())
+        );
+    }
+}
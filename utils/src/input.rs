@@ -0,0 +1,58 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{store::InputStore, Year};
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // The input store, see `InputStore`
+    Real,
+    // `days/dayN/example-input-dayN` of the year's crate
+    Example,
+    Path(PathBuf),
+    Stdin,
+}
+
+pub fn days_dir(year: &Year) -> PathBuf {
+    year.root().join("days")
+}
+
+pub fn example_input_path(year: &Year, day: u8) -> PathBuf {
+    days_dir(year)
+        .join(format!("day{day}"))
+        .join(format!("example-input-day{day}"))
+}
+
+pub(crate) fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => format!("input file {} does not exist", path.display()),
+        _ => format!("could not read input file {}: {err}", path.display()),
+    })
+}
+
+/// Reads the input of `day` of `year` from `source`, real inputs are
+/// resolved through `store`.
+pub fn load(
+    store: &InputStore,
+    year: &Year,
+    day: u8,
+    source: &InputSource,
+) -> Result<String, String> {
+    match source {
+        InputSource::Real => store.real_input(year.year, day),
+        InputSource::Example => read_file(&example_input_path(year, day)),
+        InputSource::Path(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| format!("could not read input from stdin: {err}"))?;
+
+            Ok(input)
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// A set of integers, stored as sorted inclusive ranges which neither
+/// overlap nor touch, so every value is in at most one range and adjacent
+/// ranges are merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    // `(start, end)`, both included
+    intervals: Vec<(T, T)>,
+}
+
+impl<T> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+}
+
+// Whether a range ending at `end` overlaps or is directly followed by one
+// starting at `start`. Short-circuits before `end + 1` could overflow
+fn touches<T: Copy + Ord + Add<Output = T> + From<u8>>(end: T, start: T) -> bool {
+    end >= start || end + T::from(1) == start
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8>> IntervalSet<T> {
+    /// Adds every value of `range`, merging it with the ranges it overlaps
+    /// or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self
+            .intervals
+            .partition_point(|&(_, other_end)| !touches(other_end, start));
+        let last = self
+            .intervals
+            .partition_point(|&(other_start, _)| touches(end, other_start));
+        if first < last {
+            start = start.min(self.intervals[first].0);
+            end = end.max(self.intervals[last - 1].1);
+        }
+
+        self.intervals.splice(first..last, [(start, end)]);
+    }
+
+    /// Removes every value of `range`, splitting the ranges it cuts through.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let first = self
+            .intervals
+            .partition_point(|&(_, other_end)| other_end < start);
+        let last = self
+            .intervals
+            .partition_point(|&(other_start, _)| other_start <= end);
+        if first == last {
+            return;
+        }
+
+        let one = T::from(1);
+        let mut rest = vec![];
+        let (first_start, _) = self.intervals[first];
+        if first_start < start {
+            rest.push((first_start, start - one));
+        }
+        let (_, last_end) = self.intervals[last - 1];
+        if last_end > end {
+            rest.push((end + one, last_end));
+        }
+
+        self.intervals.splice(first..last, rest);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.intervals.partition_point(|&(_, end)| end < value);
+        self.intervals
+            .get(idx)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+
+        union
+    }
+
+    /// The values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+
+        difference
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T {
+        let one = T::from(1);
+        self.intervals
+            .iter()
+            .fold(T::from(0), |len, &(start, end)| len + (end - start) + one)
+    }
+
+    /// The ranges of the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8>> FromIterator<RangeInclusive<T>>
+    for IntervalSet<T>
+{
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<RangeInclusive<i64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_touching_ranges() {
+        let mut set: IntervalSet<i64> = [12..=12, 2..=14, -2..=2, 16..=24, 14..=18, 26..=26]
+            .into_iter()
+            .collect();
+        assert_eq!(ranges(&set), [-2..=24, 26..=26]);
+        assert_eq!(set.len(), 28);
+
+        set.insert(25..=25);
+        assert_eq!(ranges(&set), [-2..=26]);
+
+        // Empty ranges are no-ops
+        #[allow(clippy::reversed_empty_ranges)]
+        set.insert(40..=30);
+        assert_eq!(ranges(&set), [-2..=26]);
+    }
+
+    #[test]
+    fn remove_splits_ranges() {
+        let mut set: IntervalSet<i64> = [0..=10, 20..=30].into_iter().collect();
+
+        set.remove(5..=5);
+        set.remove(9..=21);
+        assert_eq!(ranges(&set), [0..=4, 6..=8, 22..=30]);
+        assert!(set.contains(4) && !set.contains(5) && set.contains(22));
+
+        set.remove(i64::MIN..=i64::MAX);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn union_and_difference() {
+        let a: IntervalSet<i64> = [0..=4, 10..=14].into_iter().collect();
+        let b: IntervalSet<i64> = [3..=11].into_iter().collect();
+
+        assert_eq!(ranges(&a.union(&b)), [0..=14]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=2, 12..=14]);
+        assert_eq!(ranges(&b.difference(&a)), [5..=9]);
+    }
+
+    #[test]
+    fn ranges_reaching_the_limits_do_not_overflow() {
+        let mut set: IntervalSet<u8> = [250..=255, 0..=3].into_iter().collect();
+        set.insert(4..=4);
+        set.remove(0..=0);
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), [1..=4, 250..=255]);
+        assert_eq!(set.len(), 10);
+    }
+}
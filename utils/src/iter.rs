@@ -0,0 +1,98 @@
+//! Stable replacements for the nightly-only `Iterator::array_chunks` and
+//! `Iterator::intersperse`.
+
+use std::iter::Peekable;
+
+pub trait IterExt: Iterator + Sized {
+    /// Yields the items `N` at a time, a last chunk with fewer than `N`
+    /// items is dropped.
+    fn chunks_of<const N: usize>(self) -> ChunksOf<Self, N> {
+        ChunksOf { iter: self }
+    }
+
+    /// Yields `separator` between every two items.
+    fn separated_by(self, separator: Self::Item) -> SeparatedBy<Self>
+    where
+        Self::Item: Clone,
+    {
+        SeparatedBy {
+            iter: self.peekable(),
+            separator,
+            needs_separator: false,
+        }
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+pub struct ChunksOf<I, const N: usize> {
+    iter: I,
+}
+
+impl<I: Iterator, const N: usize> Iterator for ChunksOf<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(N).collect();
+        chunk.try_into().ok()
+    }
+}
+
+pub struct SeparatedBy<I: Iterator> {
+    iter: Peekable<I>,
+    separator: I::Item,
+    // Set after an item, the separator is only yielded if another follows
+    needs_separator: bool,
+}
+
+impl<I> Iterator for SeparatedBy<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needs_separator && self.iter.peek().is_some() {
+            self.needs_separator = false;
+            return Some(self.separator.clone());
+        }
+
+        self.needs_separator = true;
+        self.iter.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_of_drops_incomplete_chunk() {
+        let chunks: Vec<[u8; 3]> = (1..=7).chunks_of::<3>().collect();
+
+        assert_eq!(chunks, vec![[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    fn chunks_of_empty_iterator() {
+        assert_eq!(std::iter::empty::<u8>().chunks_of::<2>().next(), None);
+    }
+
+    #[test]
+    fn separated_by_only_goes_between_items() {
+        let path: String = ["", "a", "e"].into_iter().separated_by("/").collect();
+
+        assert_eq!(path, "/a/e");
+        assert_eq!(
+            std::iter::once("a").separated_by("/").collect::<String>(),
+            "a"
+        );
+        assert_eq!(
+            std::iter::empty::<&str>()
+                .separated_by("/")
+                .collect::<String>(),
+            ""
+        );
+    }
+}
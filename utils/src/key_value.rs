@@ -0,0 +1,46 @@
+//! Files of `<key> = <value>` lines, the config file and the puzzle
+//! parameters. Empty lines and `#` comments are skipped.
+
+use std::{fs, io, path::Path};
+
+/// The 1-based line number, key and value of every entry, both trimmed.
+pub(crate) fn parse(text: &str) -> Result<Vec<(usize, &str, &str)>, String> {
+    text.lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_number, line)| {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {line_number}: expected `<key> = <value>`"))?;
+
+            Ok((line_number, key.trim(), value.trim()))
+        })
+        .collect()
+}
+
+/// Reads `path`, `None` if it does not exist. Such files are optional, a
+/// missing one is the same as an empty one.
+pub(crate) fn read_optional(path: &Path) -> Result<Option<String>, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(format!("could not read {}: {err}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_comments_and_empty_lines() {
+        let entries = parse("# comment\n\n  name = value = more\nempty=\n").unwrap();
+
+        assert_eq!(entries, [(3, "name", "value = more"), (4, "empty", "")]);
+        assert_eq!(
+            parse("key value"),
+            Err("line 1: expected `<key> = <value>`".to_string())
+        );
+    }
+}
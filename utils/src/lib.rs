@@ -0,0 +1,33 @@
+//! Building blocks shared by the puzzle days of every year, and the
+//! plumbing the runner uses to load their inputs and check their answers.
+
+pub mod answers;
+pub mod config;
+pub mod coordinate;
+pub mod diamond;
+pub mod grid;
+pub mod http;
+pub mod image;
+pub mod input;
+pub mod interval_set;
+pub mod iter;
+mod key_value;
+pub mod matrix;
+pub mod parallel;
+pub mod params;
+pub mod parse;
+pub mod report;
+pub mod solution;
+pub mod sparse_grid;
+pub mod store;
+pub mod submit;
+pub mod visualize;
+pub mod year;
+
+pub use coordinate::Coordinate;
+pub use diamond::Diamond;
+pub use grid::Grid;
+pub use interval_set::IntervalSet;
+pub use parse::ParseError;
+pub use sparse_grid::SparseGrid;
+pub use year::{Day, Year};
@@ -0,0 +1,38 @@
+//! Helpers for grids stored as `Vec<Vec<T>>`, indexed `[row][col]`.
+
+/// Swaps rows and columns, rows shorter than the first one only contribute
+/// to the columns they reach.
+pub fn transpose<T>(matrix: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    let Some(len) = matrix.first().map(Vec::len) else {
+        return vec![];
+    };
+
+    let mut rows: Vec<_> = matrix.into_iter().map(Vec::into_iter).collect();
+    (0..len)
+        .map(|_| rows.iter_mut().filter_map(Iterator::next).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transposes_rectangular_matrix() {
+        let matrix = vec![vec![1, 2, 3], vec![4, 5, 6]];
+
+        assert_eq!(transpose(matrix), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn transposes_empty_matrix() {
+        assert_eq!(transpose(Vec::<Vec<u8>>::new()), Vec::<Vec<u8>>::new());
+    }
+
+    #[test]
+    fn transposing_twice_is_identity() {
+        let matrix = vec![vec!['a', 'b'], vec!['c', 'd'], vec!['e', 'f']];
+
+        assert_eq!(transpose(transpose(matrix.clone())), matrix);
+    }
+}
@@ -0,0 +1,125 @@
+//! A small pool of scoped worker threads for independent work items, such
+//! as whole days in `aoc run --all` or the searches inside a day.
+//!
+//! Results always come back in the order of the items, so output built from
+//! them does not depend on which worker finished first.
+
+use std::{
+    cell::Cell,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use crate::visualize;
+
+// 0 uses one worker per available core
+static WORKERS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static IN_WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets the number of workers used by `map`, 1 runs everything on the
+/// calling thread.
+pub fn set_workers(workers: NonZeroUsize) {
+    WORKERS.store(workers.get(), Ordering::Relaxed);
+}
+
+/// The number of workers used by `map`.
+pub fn workers() -> usize {
+    match WORKERS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        workers => workers,
+    }
+}
+
+/// Applies `f` to every item on the workers and returns the results in the
+/// order of `items`.
+///
+/// Calls made from inside a worker run on that worker, so nested maps never
+/// start more threads than `workers()`. Frames pushed for a visualisation
+/// only reach the sink of the calling thread, so `map` also stays on it
+/// while one is installed.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = workers().min(items.len());
+    if workers <= 1 || IN_WORKER.with(Cell::get) || visualize::capturing() {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                IN_WORKER.with(|in_worker| in_worker.set(true));
+
+                // Workers take the next item as soon as they are done, so a
+                // slow item does not hold up a whole share of the others
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(idx) else {
+                        break;
+                    };
+
+                    let result = f(item);
+                    results.lock().unwrap()[idx] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every item is mapped"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, thread::ThreadId};
+
+    use super::*;
+
+    #[test]
+    fn results_keep_the_order_of_the_items() {
+        let items: Vec<u64> = (0..100).collect();
+
+        let squares = map(&items, |&item| {
+            // Later items finish first
+            thread::sleep(std::time::Duration::from_micros(100 - item));
+            item * item
+        });
+
+        assert_eq!(
+            squares,
+            items.iter().map(|item| item * item).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn nested_maps_stay_on_their_worker() {
+        let items: Vec<u32> = (0..8).collect();
+
+        let nested = map(&items, |_| {
+            let outer = thread::current().id();
+            let inner: HashSet<ThreadId> = map(&items, |_| thread::current().id())
+                .into_iter()
+                .collect();
+            inner == HashSet::from([outer])
+        });
+
+        assert!(nested.into_iter().all(|same_thread| same_thread));
+    }
+}
@@ -0,0 +1,111 @@
+//! Puzzle parameters, values the puzzle text gives next to the input which
+//! differ between the example and the real input, e.g. the row 2022 day 15
+//! asks about.
+//!
+//! They are declared in `days/dayN/example-params-dayN` for the example
+//! input and in `days/dayN/params-dayN` for the real one, and can be
+//! overridden on the command line. Every non-empty line which is not a `#`
+//! comment has the form `<name> = <value>`.
+
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr};
+
+use crate::{
+    input::{self, InputSource},
+    key_value, ParseError, Year,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn parse(params: &str) -> Result<Self, String> {
+        let mut parsed = Params::new();
+        for (_, name, value) in key_value::parse(params)? {
+            parsed.set(name, value);
+        }
+
+        Ok(parsed)
+    }
+
+    /// Sets `name` to `value`, replacing any value it had.
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    /// The value of `name`. Parameters are read while parsing the input, so
+    /// a missing or invalid one is a parse error.
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, ParseError> {
+        let value = self.values.get(name).ok_or_else(|| {
+            ParseError::without_location(format!("missing puzzle parameter `{name}`"))
+        })?;
+
+        value.parse().map_err(|_| {
+            ParseError::without_location(format!(
+                "invalid value {value:?} for puzzle parameter `{name}`"
+            ))
+        })
+    }
+}
+
+pub fn example_params_path(year: &Year, day: u8) -> PathBuf {
+    input::days_dir(year)
+        .join(format!("day{day}"))
+        .join(format!("example-params-day{day}"))
+}
+
+pub fn params_path(year: &Year, day: u8) -> PathBuf {
+    input::days_dir(year)
+        .join(format!("day{day}"))
+        .join(format!("params-day{day}"))
+}
+
+/// Reads the parameters of `day` of `year` for an input read from `source`.
+/// Inputs other than the example use the parameters of the real input, a
+/// missing file is the same as an empty one.
+pub fn load(year: &Year, day: u8, source: &InputSource) -> Result<Params, String> {
+    let path = match source {
+        InputSource::Example => example_params_path(year, day),
+        InputSource::Real | InputSource::Path(_) | InputSource::Stdin => params_path(year, day),
+    };
+
+    match key_value::read_optional(&path)? {
+        Some(params) => Params::parse(&params).map_err(|err| format!("{}: {err}", path.display())),
+        None => Ok(Params::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_overrides_values() {
+        let mut params = Params::parse("# the row to check\nrow = 10\n\nname=beacon\n").unwrap();
+        assert_eq!(params.get::<i64>("row"), Ok(10));
+        assert_eq!(params.get::<String>("name").as_deref(), Ok("beacon"));
+
+        params.set("row", "2000000");
+        assert_eq!(params.get::<i64>("row"), Ok(2000000));
+    }
+
+    #[test]
+    fn missing_and_invalid_values_are_parse_errors() {
+        let params = Params::parse("row = ten").unwrap();
+
+        assert_eq!(
+            params.get::<i64>("bound").unwrap_err().message,
+            "missing puzzle parameter `bound`"
+        );
+        assert_eq!(
+            params.get::<i64>("row").unwrap_err().message,
+            "invalid value \"ten\" for puzzle parameter `row`"
+        );
+        assert!(Params::parse("row 10").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+//! Parse errors pointing at the offending text of a puzzle input, and a few
+//! helpers building them.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::Grid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    // Both 1-based
+    pub line: usize,
+    pub column: usize,
+    // The whole line containing the offending text
+    pub line_text: String,
+    // Number of characters to underline, at least 1
+    pub len: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // Filled in by the runner, the parsers don't know which day they are
+    pub day: Option<u8>,
+    pub message: String,
+    // `None` when the offending text is not part of the input
+    pub location: Option<Location>,
+}
+
+impl ParseError {
+    /// An error about `fragment`, which has to be a slice of `input` for
+    /// the error to know its line and column.
+    pub fn new(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            message: message.into(),
+            location: locate(input, fragment),
+        }
+    }
+
+    /// An error about the input as a whole, e.g. something missing from it.
+    pub fn without_location(message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+fn locate(input: &str, fragment: &str) -> Option<Location> {
+    let start = (fragment.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+    if start + fragment.len() > input.len() {
+        return None;
+    }
+
+    let line_start = input[..start].rfind('\n').map_or(0, |idx| idx + 1);
+    let line_end = input[start..]
+        .find('\n')
+        .map_or(input.len(), |idx| start + idx);
+
+    // A fragment spanning several lines is underlined until the line ends
+    let underlined = &input[start..(start + fragment.len()).min(line_end)];
+
+    Some(Location {
+        line: input[..start].matches('\n').count() + 1,
+        column: input[line_start..start].chars().count() + 1,
+        line_text: input[line_start..line_end].to_string(),
+        len: underlined.chars().count().max(1),
+    })
+}
+
+/// Renders as a diagnostic underlining the offending text, e.g.
+///
+/// ```text
+/// day 5, line 12, column 6: expected a number
+///    |
+/// 12 | move x from 1 to 2
+///    |      ^
+/// ```
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+
+        let Some(location) = &self.location else {
+            return write!(f, "invalid input: {}", self.message);
+        };
+
+        let gutter = " ".repeat(location.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            location.line, location.column, self.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", location.line, location.line_text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(location.column - 1),
+            "^".repeat(location.len)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `fragment`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, fragment: &str) -> Result<T, ParseError> {
+    fragment
+        .parse::<T>()
+        .map_err(|_| ParseError::new(input, fragment, "expected a number"))
+}
+
+/// Splits `fragment`, a slice of `input`, around the first `delimiter`.
+pub fn split_once<'a>(
+    input: &str,
+    fragment: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    fragment
+        .split_once(delimiter)
+        .ok_or_else(|| ParseError::new(input, fragment, format!("expected {delimiter:?}")))
+}
+
+/// Parses one grid row per non-empty line of `input`. `parse_cell` returns
+/// `None` for characters which are not a valid cell, described by
+/// `expected`.
+pub fn grid<T>(
+    input: &str,
+    expected: &str,
+    parse_cell: impl Fn(char) -> Option<T>,
+) -> Result<Grid<T>, ParseError> {
+    let lines: Vec<&str> = input
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    let cols = lines.first().map_or(0, |line| line.chars().count());
+
+    let mut rows = vec![];
+    for line in lines {
+        let row = line
+            .char_indices()
+            .map(|(idx, ch)| {
+                parse_cell(ch).ok_or_else(|| {
+                    ParseError::new(input, &line[idx..idx + ch.len_utf8()], expected)
+                })
+            })
+            .collect::<Result<Vec<T>, ParseError>>()?;
+
+        if row.len() != cols {
+            return Err(ParseError::new(
+                input,
+                line,
+                format!("expected {cols} cells like the first row"),
+            ));
+        }
+
+        rows.push(row);
+    }
+
+    Ok(Grid::from_rows(rows).expect("every row has the same length"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_fragment_inside_input() {
+        let input = "1-2,3-4\n5-6,x-8\n";
+        let fragment = &input[12..13];
+
+        let err = number::<u32>(input, fragment).unwrap_err();
+
+        assert_eq!(
+            err.location,
+            Some(Location {
+                line: 2,
+                column: 5,
+                line_text: "5-6,x-8".to_string(),
+                len: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn renders_caret_under_offending_text() {
+        let input = "move 1 from 2 to 3\nmove xy from 1 to 2";
+        let err = number::<u32>(input, &input[24..26])
+            .unwrap_err()
+            .with_day(5);
+
+        assert_eq!(
+            err.to_string(),
+            "day 5, line 2, column 6: expected a number\n  |\n2 | move xy from 1 to 2\n  |      ^^"
+        );
+    }
+
+    #[test]
+    fn empty_fragment_still_gets_a_caret() {
+        let input = "abc";
+        let err = ParseError::new(input, &input[3..], "unexpected end of input");
+
+        assert!(err.to_string().ends_with("|    ^"));
+    }
+
+    #[test]
+    fn grid_points_at_invalid_cell() {
+        let input = "123\n4x6\n";
+        let err = grid(input, "expected a digit", |ch| ch.to_digit(10)).unwrap_err();
+
+        assert_eq!(err.location.map(|loc| (loc.line, loc.column)), Some((2, 2)));
+    }
+
+    #[test]
+    fn grid_rejects_ragged_rows() {
+        let input = "123\n45\n";
+        let err = grid(input, "expected a digit", |ch| ch.to_digit(10)).unwrap_err();
+
+        assert_eq!(err.message, "expected 3 cells like the first row");
+    }
+
+    #[test]
+    fn fragment_outside_input_has_no_location() {
+        let err = split_once("a b", "owned copy", ":").unwrap_err();
+
+        assert_eq!(err.location, None);
+        assert_eq!(err.to_string(), "invalid input: expected \":\"");
+    }
+}
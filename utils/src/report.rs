@@ -0,0 +1,524 @@
+//! Machine readable results of a run, as JSON, CSV or JUnit XML, for
+//! dashboards and CI test reports.
+
+use std::{collections::HashMap, fmt::Write, time::Duration};
+
+use crate::solution::Part;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+    Junit,
+}
+
+impl ReportFormat {
+    pub fn parse(format: &str) -> Option<Self> {
+        match format {
+            "json" => Some(ReportFormat::Json),
+            "csv" => Some(ReportFormat::Csv),
+            "junit" => Some(ReportFormat::Junit),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Passed,
+    // The answer differs from the recorded one
+    Failed,
+    // The day could not be run, e.g. its input is missing or invalid
+    Errored,
+    // No answer is recorded for the input
+    Unchecked,
+}
+
+impl Status {
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Passed => "pass",
+            Status::Failed => "fail",
+            Status::Errored => "error",
+            Status::Unchecked => "unchecked",
+        }
+    }
+}
+
+/// The outcome of one part of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    // The answer, or why the day could not be run
+    pub answer: Result<String, String>,
+    // `None` when no answer is recorded for the input
+    pub expected: Option<String>,
+    pub parse_elapsed: Duration,
+    pub solve_elapsed: Duration,
+}
+
+impl Record {
+    pub fn status(&self) -> Status {
+        match (&self.answer, &self.expected) {
+            (Err(_), _) => Status::Errored,
+            (Ok(_), None) => Status::Unchecked,
+            (Ok(answer), Some(expected)) if answer == expected => Status::Passed,
+            (Ok(_), Some(_)) => Status::Failed,
+        }
+    }
+
+    fn error(&self) -> Option<&str> {
+        self.answer.as_ref().err().map(String::as_str)
+    }
+}
+
+pub fn encode(format: ReportFormat, records: &[Record]) -> String {
+    match format {
+        ReportFormat::Json => to_json(records),
+        ReportFormat::Csv => to_csv(records),
+        ReportFormat::Junit => to_junit(records),
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut escaped = String::from('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if u32::from(ch) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", u32::from(ch));
+            }
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_optional(text: Option<&str>) -> String {
+    text.map_or_else(|| "null".to_string(), json_string)
+}
+
+/// A JSON array with one object per record. Times are in nanoseconds, a
+/// missing answer, expected answer or error is `null`.
+pub fn to_json(records: &[Record]) -> String {
+    let objects: Vec<String> = records
+        .iter()
+        .map(|record| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"expected\": {}, \
+                 \"status\": \"{}\", \"parse_nanos\": {}, \"solve_nanos\": {}, \"error\": {}}}",
+                record.year,
+                record.day,
+                record.part.number(),
+                json_optional(record.answer.as_deref().ok()),
+                json_optional(record.expected.as_deref()),
+                record.status().name(),
+                record.parse_elapsed.as_nanos(),
+                record.solve_elapsed.as_nanos(),
+                json_optional(record.error()),
+            )
+        })
+        .collect();
+
+    if objects.is_empty() {
+        return "[]\n".to_string();
+    }
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+// The values `to_json` writes
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum JsonValue {
+    String(String),
+    Number(u64),
+    Null,
+}
+
+// Reads the JSON written by `to_json`, every method returns `None` on
+// anything else
+struct JsonReader<'a> {
+    rest: &'a str,
+}
+
+impl JsonReader<'_> {
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    // Consumes `token` if it comes next
+    fn eat(&mut self, token: char) -> bool {
+        self.skip_whitespace();
+        match self.rest.strip_prefix(token) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if !self.eat('"') {
+            return None;
+        }
+
+        let mut string = String::new();
+        let mut chars = self.rest.char_indices();
+        while let Some((idx, ch)) = chars.next() {
+            match ch {
+                '"' => {
+                    self.rest = &self.rest[idx + 1..];
+                    return Some(string);
+                }
+                '\\' => match chars.next()?.1 {
+                    'n' => string.push('\n'),
+                    'r' => string.push('\r'),
+                    't' => string.push('\t'),
+                    'u' => {
+                        let hex: String = (0..4)
+                            .map(|_| chars.next().map(|(_, ch)| ch))
+                            .collect::<Option<_>>()?;
+                        string.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                    }
+                    escaped @ ('"' | '\\' | '/') => string.push(escaped),
+                    _ => return None,
+                },
+                ch => string.push(ch),
+            }
+        }
+
+        None
+    }
+
+    fn value(&mut self) -> Option<JsonValue> {
+        self.skip_whitespace();
+        if self.rest.starts_with('"') {
+            return self.string().map(JsonValue::String);
+        }
+        if let Some(rest) = self.rest.strip_prefix("null") {
+            self.rest = rest;
+            return Some(JsonValue::Null);
+        }
+
+        let digits = self
+            .rest
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(self.rest.len());
+        let number = self.rest[..digits].parse().ok()?;
+        self.rest = &self.rest[digits..];
+        Some(JsonValue::Number(number))
+    }
+
+    fn object(&mut self) -> Option<HashMap<String, JsonValue>> {
+        let mut object = HashMap::new();
+        if !self.eat('{') {
+            return None;
+        }
+        if self.eat('}') {
+            return Some(object);
+        }
+
+        loop {
+            let key = self.string()?;
+            if !self.eat(':') {
+                return None;
+            }
+            object.insert(key, self.value()?);
+
+            if self.eat('}') {
+                return Some(object);
+            }
+            if !self.eat(',') {
+                return None;
+            }
+        }
+    }
+}
+
+fn json_record(object: &HashMap<String, JsonValue>) -> Option<Record> {
+    let number = |key: &str| match object.get(key)? {
+        JsonValue::Number(number) => Some(*number),
+        _ => None,
+    };
+    let optional_string = |key: &str| match object.get(key)? {
+        JsonValue::String(string) => Some(Some(string.clone())),
+        JsonValue::Null => Some(None),
+        JsonValue::Number(_) => None,
+    };
+
+    let answer = match (optional_string("answer")?, optional_string("error")?) {
+        (Some(answer), None) => Ok(answer),
+        (None, Some(err)) => Err(err),
+        _ => return None,
+    };
+
+    Some(Record {
+        year: number("year")?.try_into().ok()?,
+        day: number("day")?.try_into().ok()?,
+        part: Part::from_number(number("part")?.try_into().ok()?)?,
+        answer,
+        expected: optional_string("expected")?,
+        parse_elapsed: Duration::from_nanos(number("parse_nanos")?),
+        solve_elapsed: Duration::from_nanos(number("solve_nanos")?),
+    })
+}
+
+/// Reads back the records of a report written by `to_json`, `None` if
+/// `json` is not such a report.
+pub fn from_json(json: &str) -> Option<Vec<Record>> {
+    let mut reader = JsonReader { rest: json };
+    if !reader.eat('[') {
+        return None;
+    }
+
+    let mut records = vec![];
+    if !reader.eat(']') {
+        loop {
+            records.push(json_record(&reader.object()?)?);
+            if reader.eat(']') {
+                break;
+            }
+            if !reader.eat(',') {
+                return None;
+            }
+        }
+    }
+
+    reader.skip_whitespace();
+    reader.rest.is_empty().then_some(records)
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// A header line, then one line per record. Times are in nanoseconds,
+/// missing values are empty fields.
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv =
+        "year,day,part,answer,expected,status,parse_nanos,solve_nanos,error\n".to_string();
+
+    for record in records {
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part.number(),
+            csv_field(record.answer.as_deref().unwrap_or("")),
+            csv_field(record.expected.as_deref().unwrap_or("")),
+            record.status().name(),
+            record.parse_elapsed.as_nanos(),
+            record.solve_elapsed.as_nanos(),
+            csv_field(record.error().unwrap_or("")),
+        );
+    }
+
+    csv
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn seconds(elapsed: Duration) -> String {
+    format!("{:.6}", elapsed.as_secs_f64())
+}
+
+/// One test suite per year and one test case per part. Wrong answers are
+/// failures, days which could not be run are errors and parts without a
+/// recorded answer are skipped test cases.
+pub fn to_junit(records: &[Record]) -> String {
+    let count = |records: &[&Record], status: Status| {
+        records
+            .iter()
+            .filter(|record| record.status() == status)
+            .count()
+    };
+
+    let mut years: Vec<u16> = records.iter().map(|record| record.year).collect();
+    years.dedup();
+
+    let all: Vec<&Record> = records.iter().collect();
+    let mut junit = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuites tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">\n",
+        all.len(),
+        count(&all, Status::Failed),
+        count(&all, Status::Errored),
+        count(&all, Status::Unchecked)
+    );
+
+    for year in years {
+        let suite: Vec<&Record> = records
+            .iter()
+            .filter(|record| record.year == year)
+            .collect();
+        let time = suite
+            .iter()
+            .map(|record| record.parse_elapsed + record.solve_elapsed)
+            .sum();
+        let _ = writeln!(
+            junit,
+            "  <testsuite name=\"{year}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" \
+             time=\"{}\">",
+            suite.len(),
+            count(&suite, Status::Failed),
+            count(&suite, Status::Errored),
+            count(&suite, Status::Unchecked),
+            seconds(time)
+        );
+
+        for record in suite {
+            let _ = write!(
+                junit,
+                "    <testcase classname=\"{year}.day{}\" name=\"part {}\" time=\"{}\">",
+                record.day,
+                record.part.number(),
+                seconds(record.parse_elapsed + record.solve_elapsed)
+            );
+
+            match (&record.answer, &record.expected) {
+                (Err(err), _) => {
+                    let _ = write!(junit, "\n      <error message=\"{}\"/>", xml_escape(err));
+                }
+                (Ok(answer), Some(expected)) if answer != expected => {
+                    let _ = write!(
+                        junit,
+                        "\n      <failure message=\"{}\"/>",
+                        xml_escape(&format!("expected {expected:?}, got {answer:?}"))
+                    );
+                }
+                (Ok(_), None) => {
+                    junit.push_str("\n      <skipped message=\"no recorded answer\"/>")
+                }
+                (Ok(_), Some(_)) => {}
+            }
+            if let Ok(answer) = &record.answer {
+                let _ = write!(
+                    junit,
+                    "\n      <system-out>{}</system-out>",
+                    xml_escape(answer)
+                );
+            }
+
+            junit.push_str("\n    </testcase>\n");
+        }
+
+        junit.push_str("  </testsuite>\n");
+    }
+
+    junit.push_str("</testsuites>\n");
+    junit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                year: 2022,
+                day: 1,
+                part: Part::One,
+                answer: Ok("24000".to_string()),
+                expected: Some("24000".to_string()),
+                parse_elapsed: Duration::from_micros(20),
+                solve_elapsed: Duration::from_nanos(1500),
+            },
+            Record {
+                year: 2022,
+                day: 10,
+                part: Part::Two,
+                answer: Ok("#.\n.#".to_string()),
+                expected: Some("\"#\"".to_string()),
+                parse_elapsed: Duration::ZERO,
+                solve_elapsed: Duration::from_millis(2),
+            },
+            Record {
+                year: 2022,
+                day: 11,
+                part: Part::One,
+                answer: Err("could not read <input>".to_string()),
+                expected: None,
+                parse_elapsed: Duration::ZERO,
+                solve_elapsed: Duration::ZERO,
+            },
+        ]
+    }
+
+    #[test]
+    fn status_compares_with_expected_answer() {
+        let statuses: Vec<Status> = records().iter().map(Record::status).collect();
+        assert_eq!(statuses, [Status::Passed, Status::Failed, Status::Errored]);
+
+        let mut unchecked = records().remove(0);
+        unchecked.expected = None;
+        assert_eq!(unchecked.status(), Status::Unchecked);
+    }
+
+    #[test]
+    fn json_escapes_answers() {
+        assert_eq!(
+            to_json(&records()[1..2]),
+            "[\n  {\"year\": 2022, \"day\": 10, \"part\": 2, \"answer\": \"#.\\n.#\", \
+             \"expected\": \"\\\"#\\\"\", \"status\": \"fail\", \"parse_nanos\": 0, \
+             \"solve_nanos\": 2000000, \"error\": null}\n]\n"
+        );
+    }
+
+    #[test]
+    fn json_reads_back() {
+        let mut records = records();
+        records[0].answer = Ok("tab\t\u{1} \\ / ünïcode".to_string());
+
+        assert_eq!(from_json(&to_json(&records)), Some(records));
+        assert_eq!(from_json(&to_json(&[])), Some(vec![]));
+        assert_eq!(from_json("== Day 1 (parsed in 20µs) =="), None);
+        assert_eq!(from_json("[{\"year\": 2022}]"), None);
+    }
+
+    #[test]
+    fn csv_quotes_fields() {
+        assert_eq!(
+            to_csv(&records()),
+            "year,day,part,answer,expected,status,parse_nanos,solve_nanos,error\n\
+             2022,1,1,24000,24000,pass,20000,1500,\n\
+             2022,10,2,\"#.\n.#\",\"\"\"#\"\"\",fail,0,2000000,\n\
+             2022,11,1,,,error,0,0,could not read <input>\n"
+        );
+    }
+
+    #[test]
+    fn junit_reports_failures_and_errors() {
+        let junit = to_junit(&records());
+
+        assert!(
+            junit.contains("<testsuites tests=\"3\" failures=\"1\" errors=\"1\" skipped=\"0\">")
+        );
+        assert!(junit.contains(
+            "<testsuite name=\"2022\" tests=\"3\" failures=\"1\" errors=\"1\" skipped=\"0\" "
+        ));
+        assert!(junit.contains(
+            "<testcase classname=\"2022.day1\" name=\"part 1\" time=\"0.000022\">\n      \
+             <system-out>24000</system-out>\n    </testcase>"
+        ));
+        assert!(junit.contains("<failure message=\"expected &quot;\\&quot;#\\&quot;&quot;"));
+        assert!(junit.contains("<error message=\"could not read &lt;input&gt;\"/>"));
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use crate::{params::Params, ParseError};
+
+/// A puzzle day, split into a parse stage shared by both parts and one
+/// solve stage per part.
+///
+/// Only the parse stage can fail, the parts work on already validated
+/// input.
+pub trait Solution {
+    type Input;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses `input` together with the puzzle parameters given for it.
+    /// Only days with parameters implement this, see `params`.
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    // Returned by parts which have not been solved yet
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Unsolved => write!(f, "not implemented yet"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(number: $ty) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// Parses `input` once and runs the requested `parts` on it, timing every
+/// stage separately.
+pub fn solve<S: Solution>(
+    input: &str,
+    params: &Params,
+    parts: &[Part],
+) -> Result<DayResult, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse_with(input, params)?;
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&parsed).into(),
+                Part::Two => S::part2(&parsed).into(),
+            };
+
+            PartResult {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(DayResult {
+        parse_elapsed,
+        parts,
+    })
+}
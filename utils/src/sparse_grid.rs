@@ -0,0 +1,132 @@
+//! An unbounded 2D grid which only stores the cells that were set.
+
+use std::collections::{hash_map, HashMap};
+
+use crate::{Coordinate, Grid};
+
+pub type Position = Coordinate<i64>;
+
+/// Cells are keyed by signed coordinates, so the grid can grow in every
+/// direction. The bounding box of every cell ever inserted is kept up to
+/// date.
+#[derive(Debug, Clone, Default)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Position, T>,
+    // Top left and bottom right corner, inclusive
+    bounds: Option<(Position, Position)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Sets the cell at `pos`, returning its previous value.
+    pub fn insert(&mut self, pos: Position, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Coordinate::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Coordinate::new(max.x.max(pos.x), max.y.max(pos.y)),
+            ),
+            None => (pos, pos),
+        });
+
+        self.cells.insert(pos, value)
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Top left and bottom right corner of every cell inserted so far,
+    /// `None` while the grid is empty.
+    pub fn bounds(&self) -> Option<(Position, Position)> {
+        self.bounds
+    }
+
+    /// Every set cell, in no particular order.
+    pub fn iter(&self) -> hash_map::Iter<'_, Position, T> {
+        self.cells.iter()
+    }
+
+    /// Copies the bounding box into a dense grid, `draw` receives `None`
+    /// for the cells which were never set.
+    pub fn to_grid<U>(&self, draw: impl Fn(Option<&T>) -> U) -> Grid<U> {
+        let rows = match self.bounds {
+            Some((min, max)) => (min.y..=max.y)
+                .map(|y| {
+                    (min.x..=max.x)
+                        .map(|x| draw(self.get(Coordinate::new(x, y))))
+                        .collect()
+                })
+                .collect(),
+            None => vec![],
+        };
+
+        Grid::from_rows(rows).expect("every row spans the bounding box")
+    }
+
+    /// Draws the bounding box one row per line, `draw` receives `None` for
+    /// the cells which were never set.
+    pub fn render(&self, draw: impl Fn(Option<&T>) -> char) -> String {
+        self.to_grid(draw).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grows_in_every_direction() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Coordinate::new(0, 0), 'a');
+        grid.insert(Coordinate::new(-3, 2), 'b');
+        grid.insert(Coordinate::new(1_000_000, -7), 'c');
+
+        assert_eq!(
+            grid.bounds(),
+            Some((Coordinate::new(-3, -7), Coordinate::new(1_000_000, 2)))
+        );
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.get(Coordinate::new(-3, 2)), Some(&'b'));
+        assert_eq!(grid.get(Coordinate::new(-3, 3)), None);
+    }
+
+    #[test]
+    fn insert_returns_previous_value() {
+        let mut grid = SparseGrid::new();
+
+        assert_eq!(grid.insert(Coordinate::new(1, 1), 1), None);
+        assert_eq!(grid.insert(Coordinate::new(1, 1), 2), Some(1));
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn renders_only_the_occupied_area() {
+        let mut grid = SparseGrid::new();
+        grid.insert(Coordinate::new(-1, -1), '#');
+        grid.insert(Coordinate::new(1, 0), 'o');
+
+        let rendered = grid.render(|cell| cell.copied().unwrap_or('.'));
+
+        assert_eq!(rendered, "#..\n..o");
+    }
+}
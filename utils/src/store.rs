@@ -0,0 +1,132 @@
+//! Real puzzle inputs, cached on disk by year and day and downloaded on a
+//! cache miss.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::input;
+
+/// Downloads the real input of a day, see `http::HttpClient`. Stores are
+/// shared by days running concurrently, so fetchers have to be too.
+pub trait Fetch: Send + Sync {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String>;
+}
+
+/// Resolves real inputs, from the cache directory when they were seen
+/// before and from the fetcher otherwise.
+pub struct InputStore {
+    cache_dir: PathBuf,
+    // `None` keeps the store offline, only cached inputs can be read
+    fetcher: Option<Box<dyn Fetch>>,
+}
+
+impl InputStore {
+    pub fn offline(cache_dir: PathBuf) -> Self {
+        InputStore {
+            cache_dir,
+            fetcher: None,
+        }
+    }
+
+    pub fn with_fetcher(self, fetcher: impl Fetch + 'static) -> Self {
+        InputStore {
+            fetcher: Some(Box::new(fetcher)),
+            ..self
+        }
+    }
+
+    /// The `inputs/` directory at the root of the workspace.
+    pub fn default_cache_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("the utils crate is inside the workspace")
+            .join("inputs")
+    }
+
+    pub fn cached_input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("input-day{day}"))
+    }
+
+    pub fn real_input(&self, year: u16, day: u8) -> Result<String, String> {
+        let path = self.cached_input_path(year, day);
+        if path.exists() {
+            return input::read_file(&path);
+        }
+
+        let Some(fetcher) = &self.fetcher else {
+            return Err(format!(
+                "input of {year} day {day} is not cached at {} and no session token is set to download it",
+                path.display()
+            ));
+        };
+
+        let input = fetcher.fetch(year, day)?;
+
+        let year_dir = path.parent().expect("cached inputs are inside a year");
+        fs::create_dir_all(year_dir)
+            .map_err(|err| format!("could not create {}: {err}", year_dir.display()))?;
+        fs::write(&path, &input)
+            .map_err(|err| format!("could not cache input at {}: {err}", path.display()))?;
+
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, process,
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        },
+    };
+
+    use super::*;
+
+    struct CountingFetcher {
+        fetches: Arc<AtomicU32>,
+    }
+
+    impl Fetch for CountingFetcher {
+        fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+            self.fetches.fetch_add(1, Ordering::Relaxed);
+            Ok(format!("input of {year} day {day}\n"))
+        }
+    }
+
+    fn temp_cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-store-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_once_then_reads_the_cache() {
+        let cache_dir = temp_cache_dir("fetch");
+        let fetches = Arc::new(AtomicU32::new(0));
+        let store = InputStore::offline(cache_dir.clone()).with_fetcher(CountingFetcher {
+            fetches: Arc::clone(&fetches),
+        });
+
+        assert_eq!(store.real_input(2022, 3).unwrap(), "input of 2022 day 3\n");
+        assert_eq!(store.real_input(2022, 3).unwrap(), "input of 2022 day 3\n");
+        assert_eq!(fetches.load(Ordering::Relaxed), 1);
+        assert!(cache_dir.join("2022/input-day3").exists());
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn offline_store_reports_cache_miss() {
+        let store = InputStore::offline(temp_cache_dir("offline"));
+
+        let err = store.real_input(2022, 3).unwrap_err();
+
+        assert!(err.contains("no session token"), "{err}");
+    }
+}
@@ -0,0 +1,419 @@
+//! Submitting answers, with a local history of every submission so known
+//! wrong answers are never sent twice and the site's cooldown is respected.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use crate::{solution::Part, Year};
+
+/// Posts an answer and returns the response page, see `http::HttpClient`.
+pub trait Submit {
+    fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String, String>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    // Sent before the cooldown of an earlier submission ran out
+    Throttled,
+    // The part was already solved, or the previous part was not yet
+    AlreadySolved,
+}
+
+impl Verdict {
+    pub fn name(self) -> &'static str {
+        match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Throttled => "throttled",
+            Verdict::AlreadySolved => "already-solved",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "right" => Some(Verdict::Right),
+            "wrong" => Some(Verdict::Wrong),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "throttled" => Some(Verdict::Throttled),
+            "already-solved" => Some(Verdict::AlreadySolved),
+            _ => None,
+        }
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+/// One line of the submission history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    // Seconds since the Unix epoch
+    pub time: u64,
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    // Seconds the site asked to wait before the next submission
+    pub wait: u64,
+    pub answer: String,
+}
+
+impl Submission {
+    fn to_line(&self) -> String {
+        format!(
+            "{} {} {} {} {} {}",
+            self.time,
+            self.day,
+            self.part.number(),
+            self.verdict.name(),
+            self.wait,
+            self.answer.replace('\n', "\\n")
+        )
+    }
+}
+
+pub fn history_path(year: &Year) -> PathBuf {
+    year.root().join("submissions.txt")
+}
+
+/// Parses the submission history, every line has the form
+/// `<unix time> <day> <part> <verdict> <wait> <answer>`.
+pub fn parse_history(history: &str) -> Result<Vec<Submission>, String> {
+    history
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            let invalid = || {
+                format!(
+                    "submission history line {}: invalid entry {line:?}",
+                    idx + 1
+                )
+            };
+
+            let fields: Vec<&str> = line.splitn(6, ' ').collect();
+            let [time, day, part, verdict, wait, answer] = fields[..] else {
+                return Err(invalid());
+            };
+
+            Ok(Submission {
+                time: time.parse().map_err(|_| invalid())?,
+                day: day.parse().map_err(|_| invalid())?,
+                part: part
+                    .parse()
+                    .ok()
+                    .and_then(Part::from_number)
+                    .ok_or_else(invalid)?,
+                verdict: Verdict::parse(verdict).ok_or_else(invalid)?,
+                wait: wait.parse().map_err(|_| invalid())?,
+                answer: answer.replace("\\n", "\n"),
+            })
+        })
+        .collect()
+}
+
+/// Reads the submission history of `year`, there is none before the first
+/// submission.
+pub fn load_history(year: &Year) -> Result<Vec<Submission>, String> {
+    let path = history_path(year);
+    match fs::read_to_string(&path) {
+        Ok(history) => parse_history(&history),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(err) => Err(format!("could not read {}: {err}", path.display())),
+    }
+}
+
+pub fn append_history(year: &Year, submission: &Submission) -> Result<(), String> {
+    let path = history_path(year);
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut history| writeln!(history, "{}", submission.to_line()))
+        .map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+// The text of the `<article>` holding the verdict, without any markup
+fn response_text(page: &str) -> String {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+// Seconds in waits like "one minute", "5 minutes" or "1m 5s"
+fn parse_duration(text: &str) -> Option<u64> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    if let [amount, unit] = words[..] {
+        let amount = match amount {
+            "one" => Some(1),
+            "two" => Some(2),
+            "five" => Some(5),
+            "ten" => Some(10),
+            _ => amount.parse::<u64>().ok(),
+        };
+        let unit = match unit.trim_end_matches('s') {
+            "second" => Some(1),
+            "minute" => Some(60),
+            "hour" => Some(3600),
+            _ => None,
+        };
+
+        if let (Some(amount), Some(unit)) = (amount, unit) {
+            return Some(amount * unit);
+        }
+    }
+
+    words
+        .iter()
+        .map(|word| {
+            let (amount, unit) = word.split_at(word.len().checked_sub(1)?);
+            let unit = match unit {
+                "s" => 1,
+                "m" => 60,
+                "h" => 3600,
+                _ => return None,
+            };
+
+            Some(amount.parse::<u64>().ok()? * unit)
+        })
+        .sum()
+}
+
+// The wait asked for in "You have 1m 5s left to wait" or "please wait one
+// minute before trying again", zero if there is none
+fn parse_wait(text: &str) -> u64 {
+    let wait = text
+        .split_once("You have ")
+        .and_then(|(_, rest)| rest.split_once(" left to wait"))
+        .or_else(|| {
+            text.split_once("wait ")
+                .and_then(|(_, rest)| rest.split_once(" before trying again"))
+        })
+        .and_then(|(wait, _)| parse_duration(wait));
+
+    wait.unwrap_or(0)
+}
+
+/// Reads the verdict and the number of seconds to wait before the next
+/// submission out of a response page.
+pub fn parse_response(page: &str) -> Result<(Verdict, u64), String> {
+    let text = response_text(page);
+
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Right
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::Throttled
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else {
+        return Err(format!("unrecognised response: {text:?}"));
+    };
+
+    Ok((verdict, parse_wait(&text)))
+}
+
+/// Checks `answer` against the history of earlier submissions, it is
+/// refused if it is known to be wrong or if the cooldown asked for by the
+/// latest submission has not run out at `now`.
+pub fn check_submission(
+    history: &[Submission],
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<(), String> {
+    let earlier: Vec<&Submission> = history
+        .iter()
+        .filter(|submission| submission.day == day && submission.part == part)
+        .collect();
+
+    if let Some(right) = earlier
+        .iter()
+        .find(|submission| submission.verdict == Verdict::Right)
+    {
+        return Err(format!(
+            "day {day} part {} is already solved, the answer was {}",
+            part.number(),
+            right.answer
+        ));
+    }
+
+    if let Some(wrong) = earlier
+        .iter()
+        .find(|submission| submission.verdict.is_wrong() && submission.answer == answer)
+    {
+        return Err(format!(
+            "{answer} was already submitted, the verdict was {}",
+            wrong.verdict.name()
+        ));
+    }
+
+    // A number past an answer which was too high or too low is wrong too
+    if let Ok(number) = answer.parse::<i128>() {
+        for submission in &earlier {
+            let Ok(bound) = submission.answer.parse::<i128>() else {
+                continue;
+            };
+
+            let past_bound = match submission.verdict {
+                Verdict::TooHigh => number >= bound,
+                Verdict::TooLow => number <= bound,
+                _ => false,
+            };
+            if past_bound {
+                return Err(format!(
+                    "{answer} cannot be right, {bound} was already {}",
+                    submission.verdict.name()
+                ));
+            }
+        }
+    }
+
+    // The cooldown applies to every day, not only the one of the submission
+    if let Some(latest) = history.iter().max_by_key(|submission| submission.time) {
+        let ready_at = latest.time + latest.wait;
+        if ready_at > now {
+            return Err(format!(
+                "the site asked to wait before submitting again, {}s left",
+                ready_at - now
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Submits `answer` with `client` unless the history refuses it, returning
+/// the submission to add to the history.
+pub fn submit(
+    client: &dyn Submit,
+    year: u16,
+    history: &[Submission],
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Submission, String> {
+    check_submission(history, day, part, answer, now)?;
+
+    let page = client.submit(year, day, part, answer)?;
+    let (verdict, wait) = parse_response(&page)?;
+
+    Ok(Submission {
+        time: now,
+        day,
+        part,
+        verdict,
+        wait,
+        answer: answer.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(time: u64, verdict: Verdict, wait: u64, answer: &str) -> Submission {
+        Submission {
+            time,
+            day: 3,
+            part: Part::One,
+            verdict,
+            wait,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        let page = "<main><article><p>That's not the right answer; your answer is too high. \
+                    If you're stuck, ... Please wait one minute before trying again. \
+                    <a href=\"/2022/day/3\">[Return to Day 3]</a></p></article></main>";
+        assert_eq!(parse_response(page), Ok((Verdict::TooHigh, 60)));
+
+        let page = "<article><p>You gave an answer too recently; you have to wait after \
+                    submitting an answer before trying again.  You have 1m 5s left to wait. \
+                    </p></article>";
+        assert_eq!(parse_response(page), Ok((Verdict::Throttled, 65)));
+
+        let page = "<article><p>That's the right answer! You are <span>one gold star</span> \
+                    closer to saving your vacation.</p></article>";
+        assert_eq!(parse_response(page), Ok((Verdict::Right, 0)));
+
+        let page = "<article><p>You don't seem to be solving the right level.  Did you \
+                    already complete it?</p></article>";
+        assert_eq!(parse_response(page), Ok((Verdict::AlreadySolved, 0)));
+    }
+
+    #[test]
+    fn history_round_trips() {
+        let submitted = submission(1670000000, Verdict::TooLow, 300, "a\nb");
+
+        assert_eq!(parse_history(&submitted.to_line()), Ok(vec![submitted]));
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let history = [
+            submission(100, Verdict::TooHigh, 60, "500"),
+            submission(200, Verdict::TooLow, 60, "100"),
+            submission(300, Verdict::Wrong, 60, "250"),
+        ];
+
+        assert!(check_submission(&history, 3, Part::One, "250", 1000).is_err());
+        assert!(check_submission(&history, 3, Part::One, "600", 1000).is_err());
+        assert!(check_submission(&history, 3, Part::One, "100", 1000).is_err());
+        assert!(check_submission(&history, 3, Part::One, "300", 1000).is_ok());
+        assert!(check_submission(&history, 3, Part::Two, "250", 1000).is_ok());
+    }
+
+    #[test]
+    fn respects_cooldown() {
+        let history = [submission(1000, Verdict::Wrong, 60, "250")];
+
+        let err = check_submission(&history, 4, Part::One, "300", 1030).unwrap_err();
+
+        assert!(err.ends_with("30s left"), "{err}");
+        assert!(check_submission(&history, 4, Part::One, "300", 1060).is_ok());
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let history = [submission(1000, Verdict::Right, 0, "300")];
+
+        assert!(check_submission(&history, 3, Part::One, "301", 2000).is_err());
+    }
+}
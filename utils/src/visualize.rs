@@ -0,0 +1,344 @@
+//! Frames pushed by the simulations of the puzzle days, sent to whichever
+//! sink the runner installed.
+//!
+//! Days call `visualize::push` with a closure drawing the current state as
+//! a `Grid<char>`. Without an installed sink the closure is never called,
+//! so pushing frames costs nothing on normal runs.
+
+use std::{
+    cell::RefCell,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    image::{self, GifEncoder, Image, Rgb},
+    Coordinate, Grid,
+};
+
+pub type Frame = Grid<char>;
+
+/// Receives the frames of a simulation.
+pub trait FrameSink {
+    fn push(&mut self, frame: &Frame) -> Result<(), String>;
+
+    // Called once after the last frame
+    fn finish(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+// `None` while no sink is installed, errors are kept until `capture` ends
+struct Installed {
+    sink: Box<dyn FrameSink>,
+    error: Option<String>,
+}
+
+thread_local! {
+    static INSTALLED: RefCell<Option<Installed>> = const { RefCell::new(None) };
+}
+
+/// Sends the frame drawn by `frame` to the installed sink, if any.
+pub fn push(frame: impl FnOnce() -> Frame) {
+    INSTALLED.with(|installed| {
+        let mut installed = installed.borrow_mut();
+        let Some(installed) = installed.as_mut() else {
+            return;
+        };
+
+        if installed.error.is_none() {
+            if let Err(err) = installed.sink.push(&frame()) {
+                installed.error = Some(err);
+            }
+        }
+    });
+}
+
+/// Whether a sink is installed on the current thread.
+pub fn capturing() -> bool {
+    INSTALLED.with(|installed| installed.borrow().is_some())
+}
+
+/// Runs `f` with `sink` installed on the current thread, then finishes the
+/// sink. Fails with the first error of the sink.
+pub fn capture<R>(sink: Box<dyn FrameSink>, f: impl FnOnce() -> R) -> Result<R, String> {
+    INSTALLED.with(|installed| *installed.borrow_mut() = Some(Installed { sink, error: None }));
+
+    let result = f();
+
+    let Installed { mut sink, error } = INSTALLED
+        .with(|installed| installed.borrow_mut().take())
+        .expect("the sink stays installed while capturing");
+    if let Some(err) = error {
+        return Err(err);
+    }
+    sink.finish()?;
+
+    Ok(result)
+}
+
+/// Colour of a cell in image sinks. Heights `a`-`z` and digits are shades
+/// of green and blue, so heightmaps and counters stay readable.
+pub fn default_colour(ch: char) -> Rgb {
+    match ch {
+        '.' | ' ' => [16, 16, 24],
+        '#' => [230, 230, 230],
+        'o' => [235, 190, 70],
+        'S' | 's' | 'E' | 'H' => [230, 60, 60],
+        'X' => [90, 160, 250],
+        'B' => [250, 140, 40],
+        // Highlighted lines, e.g. a row the puzzle asks about
+        '=' => [240, 220, 60],
+        '-' => [120, 110, 40],
+        'a'..='z' => {
+            let level = (ch as u8 - b'a') * 7;
+            [30, 60 + level, 40]
+        }
+        '0'..='9' => {
+            let level = (ch as u8 - b'0') * 20;
+            [40, 70 + level, 200]
+        }
+        _ => [200, 80, 200],
+    }
+}
+
+/// Draws every cell as a `scale * scale` square of its colour.
+pub fn frame_to_image(frame: &Frame, scale: usize, colour: fn(char) -> Rgb) -> Image {
+    let (width, height) = (frame.cols() * scale, frame.rows() * scale);
+
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let cell = frame[Coordinate::new(x / scale, y / scale)];
+            pixels.push(colour(cell));
+        }
+    }
+
+    Image {
+        width,
+        height,
+        pixels,
+    }
+}
+
+/// Animates the frames in place in the terminal.
+pub struct TerminalSink {
+    pub delay: Duration,
+}
+
+impl FrameSink for TerminalSink {
+    fn push(&mut self, frame: &Frame) -> Result<(), String> {
+        // Clears the screen and moves the cursor to the top left corner
+        let mut stdout = io::stdout().lock();
+        writeln!(stdout, "\x1B[2J\x1B[1;1H{frame}")
+            .and_then(|_| stdout.flush())
+            .map_err(|err| format!("could not draw frame: {err}"))?;
+
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+/// Writes every frame to its own numbered image, `frame-00001.png` and so
+/// on, inside `dir`.
+pub struct ImageSequenceSink {
+    pub dir: PathBuf,
+    pub format: ImageFormat,
+    pub scale: usize,
+    frames: usize,
+}
+
+impl ImageSequenceSink {
+    pub fn new(dir: PathBuf, format: ImageFormat, scale: usize) -> Self {
+        ImageSequenceSink {
+            dir,
+            format,
+            scale,
+            frames: 0,
+        }
+    }
+}
+
+impl FrameSink for ImageSequenceSink {
+    fn push(&mut self, frame: &Frame) -> Result<(), String> {
+        if self.frames == 0 {
+            fs::create_dir_all(&self.dir)
+                .map_err(|err| format!("could not create {}: {err}", self.dir.display()))?;
+        }
+        self.frames += 1;
+
+        let image = frame_to_image(frame, self.scale, default_colour);
+        let encoded = match self.format {
+            ImageFormat::Ppm => image::encode_ppm(&image),
+            ImageFormat::Png => image::encode_png(&image),
+        };
+
+        let path = self.dir.join(format!(
+            "frame-{:05}.{}",
+            self.frames,
+            self.format.extension()
+        ));
+        fs::write(&path, encoded)
+            .map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+}
+
+/// Writes the frames into a looping animated GIF as they arrive.
+pub struct GifSink {
+    pub path: PathBuf,
+    pub scale: usize,
+    // Hundredths of a second every frame is shown for
+    pub delay: u16,
+    // Created by the first frame
+    encoder: Option<GifEncoder<BufWriter<File>>>,
+}
+
+impl GifSink {
+    pub fn new(path: PathBuf, scale: usize, delay: u16) -> Self {
+        GifSink {
+            path,
+            scale,
+            delay,
+            encoder: None,
+        }
+    }
+
+    fn create_encoder(&self) -> Result<GifEncoder<BufWriter<File>>, String> {
+        File::create(&self.path)
+            .map_err(|err| err.to_string())
+            .and_then(|file| GifEncoder::new(BufWriter::new(file), self.delay, default_colour('.')))
+            .map_err(|err| format!("could not write {}: {err}", self.path.display()))
+    }
+}
+
+impl FrameSink for GifSink {
+    fn push(&mut self, frame: &Frame) -> Result<(), String> {
+        if self.encoder.is_none() {
+            self.encoder = Some(self.create_encoder()?);
+        }
+        let encoder = self.encoder.as_mut().expect("the encoder was just created");
+
+        encoder
+            .push(&frame_to_image(frame, self.scale, default_colour))
+            .map_err(|err| format!("could not write {}: {err}", self.path.display()))
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        // Without frames this writes an empty GIF
+        let encoder = match self.encoder.take() {
+            Some(encoder) => encoder,
+            None => self.create_encoder()?,
+        };
+
+        encoder
+            .finish()
+            .map(drop)
+            .map_err(|err| format!("could not write {}: {err}", self.path.display()))
+    }
+}
+
+/// Passes on only every `every`th frame, for simulations with far more
+/// steps than anyone wants to look at.
+pub struct Sampled<S> {
+    pub sink: S,
+    pub every: usize,
+    seen: usize,
+}
+
+impl<S: FrameSink> Sampled<S> {
+    pub fn new(sink: S, every: usize) -> Self {
+        Sampled {
+            sink,
+            every,
+            seen: 0,
+        }
+    }
+}
+
+impl<S: FrameSink> FrameSink for Sampled<S> {
+    fn push(&mut self, frame: &Frame) -> Result<(), String> {
+        let skip = !self.seen.is_multiple_of(self.every.max(1));
+        self.seen += 1;
+        if skip {
+            return Ok(());
+        }
+
+        self.sink.push(frame)
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        self.sink.finish()
+    }
+}
+
+impl FrameSink for Box<dyn FrameSink> {
+    fn push(&mut self, frame: &Frame) -> Result<(), String> {
+        (**self).push(frame)
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        (**self).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+
+    struct Recorder {
+        frames: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl FrameSink for Recorder {
+        fn push(&mut self, frame: &Frame) -> Result<(), String> {
+            self.frames.borrow_mut().push(frame.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn captures_pushed_frames() {
+        let frames = Rc::new(RefCell::new(vec![]));
+        let sink = Sampled::new(
+            Recorder {
+                frames: Rc::clone(&frames),
+            },
+            2,
+        );
+
+        let answer = capture(Box::new(sink), || {
+            for ch in ['a', 'b', 'c'] {
+                push(|| Grid::new(1, 2, ch));
+            }
+            42
+        });
+
+        assert_eq!(answer, Ok(42));
+        assert_eq!(*frames.borrow(), ["aa", "cc"]);
+    }
+
+    #[test]
+    fn frames_are_not_drawn_without_sink() {
+        push(|| panic!("no sink is installed"));
+    }
+}
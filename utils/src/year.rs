@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use crate::{
+    params::Params,
+    solution::{DayResult, Part},
+    ParseError,
+};
+
+/// A registered puzzle day, `solve` parses the input with its puzzle
+/// parameters once and runs the requested parts on it.
+pub struct Day {
+    pub day: u8,
+    pub solve: fn(&str, &Params, &[Part]) -> Result<DayResult, ParseError>,
+}
+
+/// The solutions of one Advent of Code year.
+pub struct Year {
+    pub year: u16,
+    // Directory of the year's crate, holding the `days/` inputs and the
+    // answers.txt manifest
+    pub root: &'static str,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn root(&self) -> &'static Path {
+        Path::new(self.root)
+    }
+
+    pub fn find(&self, day: u8) -> Option<&'static Day> {
+        self.days.iter().find(|registered| registered.day == day)
+    }
+}